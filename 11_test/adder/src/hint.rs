@@ -0,0 +1,138 @@
+use std::cmp::Ordering;
+
+use crate::Bounded;
+
+/// 추측을 비밀 숫자와 비교한 결과. distance는 비밀 숫자까지의 거리이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    TooLow { distance: u32 },
+    TooHigh { distance: u32 },
+    Correct,
+}
+
+/// 지금까지의 비교 결과로 보아 비밀 숫자가 있을 수 있는 구간 (low 이상 high 이하)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval<const MIN: u32, const MAX: u32> {
+    low: Bounded<MIN, MAX>,
+    high: Bounded<MIN, MAX>,
+}
+
+impl<const MIN: u32, const MAX: u32> Bounded<MIN, MAX> {
+    /// 이 추측을 비밀 숫자와 비교한다.
+    pub fn compare(&self, secret: Self) -> Outcome {
+        match self.value.cmp(&secret.value) {
+            Ordering::Less => Outcome::TooLow { distance: secret.value - self.value },
+            Ordering::Greater => Outcome::TooHigh { distance: self.value - secret.value },
+            Ordering::Equal => Outcome::Correct,
+        }
+    }
+
+    /// 이미 알고 있는 구간 밖을 추측했으면 참. 이런 추측은 아무것도 알려주지 못하고 기회만 쓴다.
+    pub fn is_wasted(&self, known: &Interval<MIN, MAX>) -> bool {
+        !known.contains(*self)
+    }
+}
+
+impl<const MIN: u32, const MAX: u32> Interval<MIN, MAX> {
+    /// 아무것도 모를 때의 구간 MIN ~ MAX. MIN > MAX면 None
+    pub fn full() -> Option<Self> {
+        let low = Bounded::try_new(MIN).ok()?;
+        let high = Bounded::try_new(MAX).ok()?;
+        Some(Interval { low, high })
+    }
+
+    /// 추측과 결과의 목록으로 구간을 좁힌다. 결과끼리 모순되어 구간이 비면 None
+    pub fn from_outcomes<I>(outcomes: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (Bounded<MIN, MAX>, Outcome)>,
+    {
+        outcomes
+            .into_iter()
+            .try_fold(Interval::full()?, |interval, (guess, outcome)| interval.narrow(guess, outcome))
+    }
+
+    /// 추측 하나의 결과를 반영한 구간. 구간이 비면 None
+    pub fn narrow(self, guess: Bounded<MIN, MAX>, outcome: Outcome) -> Option<Self> {
+        let (low, high) = match outcome {
+            Outcome::TooLow { .. } => (self.low.max(guess.checked_add(1)?), self.high),
+            Outcome::TooHigh { .. } => (self.low, self.high.min(guess.checked_sub(1)?)),
+            Outcome::Correct => (self.low.max(guess), self.high.min(guess)),
+        };
+        if low > high {
+            return None;
+        }
+        Some(Interval { low, high })
+    }
+
+    pub fn low(&self) -> Bounded<MIN, MAX> {
+        self.low
+    }
+
+    pub fn high(&self) -> Bounded<MIN, MAX> {
+        self.high
+    }
+
+    pub fn contains(&self, value: Bounded<MIN, MAX>) -> bool {
+        self.low <= value && value <= self.high
+    }
+
+    /// 구간에 남은 후보 수. 빈 구간은 만들어지지 않으므로 항상 1 이상이다.
+    pub fn candidates(&self) -> u64 {
+        u64::from(self.high.value - self.low.value) + 1
+    }
+
+    /// 후보가 하나만 남았으면 그 값
+    pub fn solved(&self) -> Option<Bounded<MIN, MAX>> {
+        if self.low == self.high {
+            Some(self.low)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Guess;
+
+    #[test]
+    fn compare_with_distance() {
+        let secret = Guess::new(42);
+        assert_eq!(Guess::new(40).compare(secret), Outcome::TooLow { distance: 2 });
+        assert_eq!(Guess::new(50).compare(secret), Outcome::TooHigh { distance: 8 });
+        assert_eq!(Guess::new(42).compare(secret), Outcome::Correct);
+    }
+
+    #[test]
+    fn narrow_by_outcomes() {
+        let secret = Guess::new(42);
+        let guesses = [50, 25, 37].iter().map(|&value| Guess::new(value));
+        let interval = Interval::from_outcomes(guesses.map(|guess| (guess, guess.compare(secret)))).unwrap();
+        assert_eq!((interval.low().value(), interval.high().value()), (38, 49));
+        assert_eq!(interval.candidates(), 12);
+        assert_eq!(interval.solved(), None);
+
+        let solved = interval.narrow(secret, Outcome::Correct).unwrap();
+        assert_eq!(solved.solved(), Some(secret));
+    }
+
+    #[test]
+    fn contradiction_empties_the_interval() {
+        let outcomes = vec![
+            (Guess::new(45), Outcome::TooLow { distance: 1 }),
+            (Guess::new(40), Outcome::TooHigh { distance: 1 }),
+        ];
+        assert_eq!(Interval::from_outcomes(outcomes), None);
+        let full = Interval::<1, 100>::full().unwrap();
+        assert_eq!(full.narrow(Guess::new(100), Outcome::TooLow { distance: 1 }), None);
+    }
+
+    #[test]
+    fn wasted_guess() {
+        let known = Interval::full().unwrap().narrow(Guess::new(50), Outcome::TooHigh { distance: 8 }).unwrap();
+        assert!(Guess::new(60).is_wasted(&known));
+        assert!(Guess::new(50).is_wasted(&known));
+        assert!(!Guess::new(49).is_wasted(&known));
+    }
+}
@@ -1,17 +1,139 @@
-pub struct Guess {
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+mod hint;
+#[cfg(feature = "serde")]
+mod serialize;
+
+pub use hint::{Interval, Outcome};
+
+/// MIN 이상 MAX 이하의 값만 담을 수 있는 정수.
+/// 만들 때 범위를 검사하므로 값이 있으면 항상 범위 안이다. 산술 연산도 범위를 벗어나지 않는다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bounded<const MIN: u32, const MAX: u32> {
     value: u32
 }
 
-impl Guess {
-    pub fn new(value: u32) -> Guess {
-        if value < 1 || value > 100 {
-            panic!("Guess value must be between 1 and 100, got {}.", value);
+/// 원래의 1 ~ 100 숫자 맞추기 추측
+pub type Guess = Bounded<1, 100>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoundedError {
+    TooLow { value: u32, min: u32, max: u32 },
+    TooHigh { value: u32, min: u32, max: u32 },
+    NotANumber(String),
+}
+
+pub type GuessError = BoundedError;
+
+/// 범위를 벗어났을 때 문장의 주어. Guess(1 ~ 100)만 원래 panic 문장대로 "Guess value"이고
+/// 다른 범위는 추측이 아닐 수도 있으므로(주사위 눈 등) 그냥 "Value" 이다.
+fn subject(min: u32, max: u32) -> &'static str {
+    if (min, max) == (Guess::MIN, Guess::MAX) {
+        "Guess value"
+    } else {
+        "Value"
+    }
+}
+
+impl fmt::Display for BoundedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BoundedError::TooLow { value, min, max } | BoundedError::TooHigh { value, min, max } => {
+                write!(f, "{} must be between {} and {}, got {}.", subject(*min, *max), min, max, value)
+            }
+            BoundedError::NotANumber(text) => write!(f, "Value must be a number, got '{}'.", text),
         }
+    }
+}
 
-        Guess {
-            value
+impl Error for BoundedError {}
+
+impl<const MIN: u32, const MAX: u32> Bounded<MIN, MAX> {
+    pub const MIN: u32 = MIN;
+    pub const MAX: u32 = MAX;
+
+    /// 범위를 벗어나면 panic 한다. 호출하는 쪽에서 미리 검사하지 않으려면 try_new를 쓴다.
+    pub fn new(value: u32) -> Self {
+        match Self::try_new(value) {
+            Ok(bounded) => bounded,
+            Err(error) => panic!("{}", error),
         }
     }
+
+    pub fn try_new(value: u32) -> Result<Self, BoundedError> {
+        if value < MIN {
+            return Err(BoundedError::TooLow { value, min: MIN, max: MAX });
+        }
+        if value > MAX {
+            return Err(BoundedError::TooHigh { value, min: MIN, max: MAX });
+        }
+
+        Ok(Bounded {
+            value
+        })
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// 더한 값이 범위를 벗어나면 None
+    pub fn checked_add(self, rhs: u32) -> Option<Self> {
+        self.value.checked_add(rhs).and_then(|value| Self::try_new(value).ok())
+    }
+
+    /// 뺀 값이 범위를 벗어나면 None
+    pub fn checked_sub(self, rhs: u32) -> Option<Self> {
+        self.value.checked_sub(rhs).and_then(|value| Self::try_new(value).ok())
+    }
+
+    /// 더한 값이 MAX를 넘으면 MAX
+    pub fn saturating_add(self, rhs: u32) -> Self {
+        Bounded { value: self.value.saturating_add(rhs).min(MAX) }
+    }
+
+    /// 뺀 값이 MIN보다 작으면 MIN
+    pub fn saturating_sub(self, rhs: u32) -> Self {
+        Bounded { value: self.value.saturating_sub(rhs).max(MIN) }
+    }
+
+    /// MIN부터 MAX까지 모든 값을 작은 것부터 차례로 돌려준다. MIN > MAX면 아무것도 없다.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (MIN..=MAX).map(|value| Bounded { value })
+    }
+}
+
+impl<const MIN: u32, const MAX: u32> fmt::Display for Bounded<MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const MIN: u32, const MAX: u32> TryFrom<u32> for Bounded<MIN, MAX> {
+    type Error = BoundedError;
+
+    fn try_from(value: u32) -> Result<Self, BoundedError> {
+        Self::try_new(value)
+    }
+}
+
+impl<const MIN: u32, const MAX: u32> From<Bounded<MIN, MAX>> for u32 {
+    fn from(bounded: Bounded<MIN, MAX>) -> u32 {
+        bounded.value
+    }
+}
+
+impl<const MIN: u32, const MAX: u32> FromStr for Bounded<MIN, MAX> {
+    type Err = BoundedError;
+
+    fn from_str(s: &str) -> Result<Self, BoundedError> {
+        let text = s.trim();
+        let value = text.parse().map_err(|_| BoundedError::NotANumber(text.to_string()))?;
+        Self::try_new(value)
+    }
 }
 
 #[cfg(test)]
@@ -28,4 +150,56 @@ mod tests {
     fn lower_than_100() {
         let _g = Guess::new(99);
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[should_panic(expected = "Guess value must be between 1 and 100, got 0.")]
+    fn panic_message_is_unchanged() {
+        let _g = Guess::new(0);
+    }
+
+    #[test]
+    fn try_new_reports_the_bounds() {
+        assert_eq!(Guess::try_new(0).err(), Some(GuessError::TooLow { value: 0, min: 1, max: 100 }));
+        assert_eq!(Guess::try_new(101).err(), Some(GuessError::TooHigh { value: 101, min: 1, max: 100 }));
+        assert!(Guess::try_new(1).is_ok());
+        assert_eq!(Guess::try_from(100).map(|guess| guess.value()), Ok(100));
+    }
+
+    #[test]
+    fn parse_from_text() {
+        assert_eq!(" 42\n".parse::<Guess>(), Ok(Guess::new(42)));
+        assert_eq!("abc".parse::<Guess>().err(), Some(GuessError::NotANumber("abc".to_string())));
+        let error = "200".parse::<Guess>().err().unwrap();
+        assert_eq!(error.to_string(), "Guess value must be between 1 and 100, got 200.");
+        assert_eq!("abc".parse::<Guess>().unwrap_err().to_string(), "Value must be a number, got 'abc'.");
+    }
+
+    #[test]
+    fn other_bounds_are_not_called_guesses() {
+        assert_eq!(Bounded::<1, 6>::try_new(7).unwrap_err().to_string(), "Value must be between 1 and 6, got 7.");
+        assert_eq!(Bounded::<1, 101>::try_new(0).unwrap_err().to_string(), "Value must be between 1 and 101, got 0.");
+    }
+
+    #[test]
+    fn arithmetic_stays_in_bounds() {
+        let d6 = Bounded::<1, 6>::new(5);
+        assert_eq!(d6.checked_add(1), Some(Bounded::new(6)));
+        assert_eq!(d6.checked_add(2), None);
+        assert_eq!(d6.checked_sub(5), None);
+        assert_eq!(d6.saturating_add(u32::MAX).value(), 6);
+        assert_eq!(d6.saturating_sub(10).value(), 1);
+        assert_eq!(Bounded::<0, { u32::MAX }>::new(u32::MAX).checked_add(1), None);
+    }
+
+    #[test]
+    fn ordering_display_and_domain() {
+        assert!(Guess::new(3) < Guess::new(40));
+        assert_eq!(Guess::new(42).to_string(), "42");
+        assert_eq!(u32::from(Guess::new(42)), 42);
+        assert_eq!((Guess::MIN, Guess::MAX), (1, 100));
+        let all: Vec<u32> = Bounded::<3, 6>::all().map(|b| b.value()).collect();
+        assert_eq!(all, vec![3, 4, 5, 6]);
+        assert_eq!(Guess::all().count(), 100);
+        assert_eq!(Bounded::<5, 1>::all().count(), 0);
+    }
+}
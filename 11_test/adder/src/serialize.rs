@@ -0,0 +1,52 @@
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Bounded;
+
+/// 그냥 숫자 하나로 저장한다.
+impl<const MIN: u32, const MAX: u32> Serialize for Bounded<MIN, MAX> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.value)
+    }
+}
+
+/// derive로 만들면 범위 검사 없이 필드에 바로 넣어버리므로 try_new를 거쳐서 만든다.
+/// 범위 밖이면 panic 대신 new가 panic 할 때와 같은 문장의 역직렬화 오류가 난다.
+impl<'de, const MIN: u32, const MAX: u32> Deserialize<'de> for Bounded<MIN, MAX> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u32::deserialize(deserializer)?;
+        Bounded::try_new(value).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::Guess;
+
+    #[test]
+    fn json_round_trip() {
+        let guesses = vec![Guess::new(1), Guess::new(42), Guess::new(100)];
+        let json = serde_json::to_string(&guesses).unwrap();
+        assert_eq!(json, "[1,42,100]");
+        assert_eq!(serde_json::from_str::<Vec<Guess>>(&json).unwrap(), guesses);
+    }
+
+    #[test]
+    fn out_of_range_is_an_error() {
+        let error = serde_json::from_str::<Guess>("200").unwrap_err();
+        assert!(error.to_string().starts_with("Guess value must be between 1 and 100, got 200."));
+        assert!(serde_json::from_str::<Guess>("-1").is_err());
+        assert!(serde_json::from_str::<Guess>("\"42\"").is_err());
+    }
+
+    #[test]
+    fn toml() {
+        let table: BTreeMap<String, Guess> = toml::from_str("first = 50\nlast = 42\n").unwrap();
+        assert_eq!(table["last"], Guess::new(42));
+        assert_eq!(toml::to_string(&table).unwrap(), "first = 50\nlast = 42\n");
+        let error = toml::from_str::<BTreeMap<String, Guess>>("first = 0\n").unwrap_err();
+        assert!(error.to_string().contains("Guess value must be between 1 and 100, got 0."));
+    }
+}
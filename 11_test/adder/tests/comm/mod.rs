@@ -1,3 +1,7 @@
+// 다른 crate의 테스트와 같이 쓰는 도우미(임시 디렉터리, 가짜 입출력, panic 문장 검사)는
+// 저장소 최상위의 test_support crate로 옮겼다. 여기서는 다시 내보내기만 한다.
+pub use test_support::*;
+
 pub fn get_num() -> u32 {
     1234
-}
\ No newline at end of file
+}
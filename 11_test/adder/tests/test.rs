@@ -1,9 +1,30 @@
 extern crate adder;
+extern crate test_support;
 
 mod comm;
 
+use adder::{Bounded, Guess};
+
 #[test]
 fn integral_test() {
     let num = comm::get_num();
     assert_eq!(num, 1234);
-}
\ No newline at end of file
+}
+
+#[test]
+fn out_of_range_panic_message() {
+    for &value in &[0, 101, 200] {
+        comm::assert_panics_with(|| Guess::new(value), &comm::guess_range_message(Guess::MIN, Guess::MAX, value));
+    }
+    comm::assert_panics_with(|| Bounded::<3, 6>::new(7), &comm::range_message(3, 6, 7));
+    assert_eq!(comm::panic_message(|| Guess::new(50)), None);
+}
+
+#[test]
+fn guesses_saved_in_temp_dir() {
+    let dir = comm::TempDir::new("adder_guesses");
+    let path = dir.join("guesses.txt");
+    std::fs::write(&path, "42\n 7\n").unwrap();
+    let guesses: Vec<Guess> = std::fs::read_to_string(&path).unwrap().lines().map(|line| line.parse().unwrap()).collect();
+    assert_eq!(guesses, vec![Guess::new(42), Guess::new(7)]);
+}
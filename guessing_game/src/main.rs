@@ -1,17 +1,34 @@
-use std::io; // 표준 라이브러리의 입츌력 라이브러리를 가져옴. C++에서 using namespase와 비슷한듯
+extern crate prompt; // 같은 저장소의 prompt crate. Cargo.toml에 path로 적어두었다.
+
+use std::process;
+
+use prompt::PromptError;
 
 fn main() {
-    println!("숫자를 입력하세여"); // 아직 왜 그런진 모르겠지만 println 함수가 아니라 매크로를 가져와야 한다 함.
-    println!("뭘 입력했는지 예상해보겠습니다");
-    let mut guess = String::new(); // 러스트에서 변수는 기존적으로 불변속성인데 mut(able) 키워드를 붙이면 가변변수가 됨
+    println!("뭘 입력했는지 예상해보겠습니다"); // 아직 왜 그런진 모르겠지만 println 함수가 아니라 매크로를 가져와야 한다 함.
+    let guess: u32 = match prompt::prompt("숫자를 입력하세여", prompt::any) {
+        Ok(guess) => guess,
+        Err(PromptError::Eof) => {
+            println!("입력이 없어서 끝냅니다.");
+            return;
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
     /*
+    처음에는 이렇게 읽었다.
+
+        let mut guess = String::new();
+        io::stdin().read_line(&mut guess).expect("라인 가져오기 실패");
+
+    러스트에서 변수는 기존적으로 불변속성인데 mut(able) 키워드를 붙이면 가변변수가 됨
     String은 표준 라이브러리에서 제공하는 확장 가능한 UTF-8 문자열 타입
     new() 함수는 String의 정적 메서드이다. (C++같은 언어에서 클래스의 정적 멤버함수를 호출하는것과 동일하다.)
     하지만 러스트에선 정적 메서드가 아니라 연관 함수 (Associated function) 라고 부르는 듯 하다.
     아무튼 new 함수는 비어있는 String 타입을 생성하여 guess에 대입한다.
-    */
-    io::stdin().read_line(&mut guess).expect("라인 가져오기 실패");
-    /*
+
     io의 연관함수인 stdin() 함수를 호출한다.
     참고로 use 키워드를 사용하지 않는다면 std::io::stdin() 이렇게 써야한다.
 
@@ -28,6 +45,12 @@ fn main() {
     멤버 변수로는 Ok와 Err가 있다고 한다.
     expect 메소드는 멤버변수가 Ok라면 별 동작을 하지 않으며 Err일때에는 인수로 입력된 메시지를 출력한다. (프로그램도 중지되는듯)
     컴파일러는 expect 메소드를 붙이지 않으면 경고를 발생시킨다. (컴파일은 된다.)
+
+    그런데 이렇게 읽으면 개행까지 guess에 들어가서 그대로 출력되고, 숫자가 아니어도 그냥 넘어가며, 오류가 나면 프로그램이 죽는다.
+    그래서 지금은 prompt crate가 read_line으로 읽은 줄의 앞뒤 공백과 개행을 잘라내고 u32로 파싱한다.
+    숫자가 아니면 이유를 알려주고 다시 묻는다. (let guess: u32 로 타입을 적어주면 어떤 타입으로 파싱할지 알아낸다.)
+    입력이 끝났거나(EOF) 입출력 오류가 나면 panic 대신 Err 값을 돌려주므로 match로 나눠서 처리한다.
+    두 번째 인자는 파싱한 값을 한 번 더 검사하는 함수인데, 여기서는 아무 숫자나 받으므로 prompt::any를 넘긴다.
     */
     println!("님이 입력한 숫자는 {}입니다.", guess);
     /*
@@ -0,0 +1,20 @@
+extern crate test_support;
+
+use test_support::{run, NO_ARGS};
+
+const BIN: &str = env!("CARGO_BIN_EXE_guessing_game");
+
+#[test]
+fn echoes_the_trimmed_number() {
+    let output = run(BIN, NO_ARGS, "abc\n 42 \n");
+    assert!(output.success());
+    assert!(output.stdout.contains("'abc'을(를) 읽을 수 없습니다."));
+    assert!(output.stdout.ends_with("님이 입력한 숫자는 42입니다.\n"));
+}
+
+#[test]
+fn no_input() {
+    let output = run(BIN, NO_ARGS, "");
+    assert!(output.success());
+    assert!(output.stdout.ends_with("입력이 없어서 끝냅니다.\n"));
+}
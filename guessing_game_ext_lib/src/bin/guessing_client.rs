@@ -0,0 +1,78 @@
+extern crate guessing_game_ext_lib;
+
+use std::env;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::process;
+
+use guessing_game_ext_lib::server::{Reply, DEFAULT_ADDR};
+
+/*
+guessing_game_ext_lib --server 로 띄운 서버에 접속해서 게임을 하는 클라이언트.
+사용법: guessing_client [주소] (기본값 127.0.0.1:7878)
+
+플레이어가 입력한 줄을 GUESS 명령으로 보내고 서버의 응답을 한국어로 바꿔서 보여준다.
+맞추면 종료 코드 0, 기회를 다 쓰면 1, 접속이나 프로토콜 문제가 있으면 2로 끝난다.
+*/
+fn main() {
+    let addr = env::args().nth(1).unwrap_or_else(|| DEFAULT_ADDR.to_string());
+    let code = match run(&addr) {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("서버와 통신하지 못했습니다: {} ({})", addr, error);
+            2
+        }
+    };
+    process::exit(code);
+}
+
+fn run(addr: &str) -> io::Result<i32> {
+    let stream = TcpStream::connect(addr)?;
+    let mut server = BufReader::new(stream.try_clone()?);
+    let mut stream = stream;
+    let stdin = io::stdin();
+    let mut stdin = stdin.lock();
+
+    match read_reply(&mut server)? {
+        Some(Reply::Ready { min, max, max_attempts }) => {
+            println!("{} 부터 {} 사이의 숫자를 맞춰보세요.", min, max);
+            if let Some(max_attempts) = max_attempts {
+                println!("기회는 {}번 입니다.", max_attempts);
+            }
+        }
+        _ => return Ok(2),
+    }
+    loop {
+        println!("숫자를 입력하세여");
+        let mut guess = String::new();
+        if stdin.read_line(&mut guess)? == 0 {
+            writeln!(stream, "QUIT")?;
+            read_reply(&mut server)?;
+            return Ok(2);
+        }
+        writeln!(stream, "GUESS {}", guess.trim())?;
+        match read_reply(&mut server)? {
+            Some(Reply::Low) => println!("너무 작음"),
+            Some(Reply::High) => println!("너무 큼"),
+            Some(Reply::Error(reason)) => println!("{}", reason),
+            Some(Reply::Win(attempts)) => {
+                println!("님이 맞춤 ({}번 만에)", attempts);
+                return Ok(0);
+            }
+            Some(Reply::Lose(secret)) => {
+                println!("기회를 모두 썼습니다. 정답은 {} 였습니다.", secret);
+                return Ok(1);
+            }
+            _ => return Ok(2),
+        }
+    }
+}
+
+/// 서버의 응답 한 줄을 읽는다. 연결이 끊겼거나 알 수 없는 응답이면 None 이다.
+fn read_reply<R: BufRead>(server: &mut R) -> io::Result<Option<Reply>> {
+    let mut line = String::new();
+    if server.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Reply::parse(&line))
+}
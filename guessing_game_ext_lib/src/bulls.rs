@@ -0,0 +1,357 @@
+/*
+숫자 야구(Bulls and Cows, Mastermind 비슷한 게임).
+
+비밀 숫자는 서로 다른 숫자 4개로 이루어진 네 자리 수이다. (0으로 시작해도 된다.)
+추측마다 자리와 숫자가 모두 맞은 개수(bulls, 스트라이크)와 숫자는 있지만 자리가 다른 개수(cows, 볼)를 알려준다.
+기본 게임이 추측을 Ordering 하나로 대답하듯이 이 게임은 Feedback 하나로 대답한다.
+*/
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+use prompt::{Prompt, PromptError};
+use rand::Rng;
+
+use crate::config::Config;
+use crate::game::{write_max_attempts, write_remaining, Outcome, END_OF_INPUT};
+
+/// 비밀 숫자와 추측의 자릿수
+pub const DIGITS: usize = 4;
+
+/// 서로 다른 숫자 4개로 이루어진 비밀 숫자 또는 추측
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Code([u8; DIGITS]);
+
+impl Code {
+    /// 숫자들이 서로 다르지 않거나 0 ~ 9가 아니면 None 이다.
+    pub fn new(digits: [u8; DIGITS]) -> Option<Code> {
+        let distinct = (0..DIGITS).all(|i| !digits[i + 1..].contains(&digits[i]));
+        if distinct && digits.iter().all(|&d| d <= 9) {
+            Some(Code(digits))
+        } else {
+            None
+        }
+    }
+
+    /// 입력 한 줄을 추측으로 바꾼다. 앞뒤 공백과 개행은 무시한다.
+    pub fn parse(line: &str) -> Result<Code, CodeError> {
+        let text = line.trim();
+        if text.is_empty() {
+            return Err(CodeError::Empty);
+        }
+        if let Some(c) = text.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(CodeError::NotADigit { text: text.to_string(), found: c });
+        }
+        if text.len() != DIGITS {
+            return Err(CodeError::WrongLength(text.to_string()));
+        }
+        let mut digits = [0; DIGITS];
+        for (i, b) in text.bytes().enumerate() {
+            digits[i] = b - b'0';
+            if digits[..i].contains(&digits[i]) {
+                return Err(CodeError::RepeatedDigit { text: text.to_string(), digit: digits[i] });
+            }
+        }
+        Ok(Code(digits))
+    }
+
+    /// 주입받은 난수 생성기로 비밀 숫자를 뽑는다.
+    pub fn random<R: Rng>(rng: &mut R) -> Code {
+        let mut pool: Vec<u8> = (0..10).collect();
+        let mut digits = [0; DIGITS];
+        for digit in digits.iter_mut() {
+            *digit = pool.remove(rng.gen_range(0, pool.len()));
+        }
+        Code(digits)
+    }
+
+    /// 만들 수 있는 모든 비밀 숫자 (10 * 9 * 8 * 7 = 5040개), 작은 수부터
+    pub fn all() -> Vec<Code> {
+        (0..10_000u32)
+            .filter_map(|n| Code::new([(n / 1000) as u8, (n / 100 % 10) as u8, (n / 10 % 10) as u8, (n % 10) as u8]))
+            .collect()
+    }
+
+    /// 비밀 숫자(self)와 추측을 비교한다. cmp처럼 비밀 숫자가 아니라 추측 쪽에서 불러도 결과는 같다.
+    pub fn compare(&self, guess: &Code) -> Feedback {
+        let mut feedback = Feedback { bulls: 0, cows: 0 };
+        for (i, digit) in guess.0.iter().enumerate() {
+            if self.0[i] == *digit {
+                feedback.bulls += 1;
+            } else if self.0.contains(digit) {
+                feedback.cows += 1;
+            }
+        }
+        feedback
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for digit in self.0.iter() {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+/// 추측 하나에 대한 대답
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Feedback {
+    /// 자리와 숫자가 모두 맞은 개수 (스트라이크)
+    pub bulls: u8,
+    /// 숫자는 있지만 자리가 다른 개수 (볼)
+    pub cows: u8,
+}
+
+impl Feedback {
+    pub fn is_correct(&self) -> bool {
+        self.bulls as usize == DIGITS
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.bulls == 0 && self.cows == 0 {
+            write!(f, "아웃 (맞는 숫자가 하나도 없음)")
+        } else {
+            write!(f, "{} 스트라이크 {} 볼", self.bulls, self.cows)
+        }
+    }
+}
+
+/// 숫자 야구에서 쓸 수 없는 답. 서로 다른 숫자 네 자리가 아니면 판정하지 않고 다시 묻는다. (시도 횟수에 들어가지 않음)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodeError {
+    Empty,
+    /// 숫자가 아닌 글자가 섞여 있다.
+    NotADigit { text: String, found: char },
+    /// 네 자리가 아니다.
+    WrongLength(String),
+    /// 같은 숫자가 두 번 이상 나온다.
+    RepeatedDigit { text: String, digit: u8 },
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CodeError::Empty => write!(f, "아무것도 입력하지 않았습니다. 네 자리 숫자를 입력해주세요."),
+            CodeError::NotADigit { text, found } => write!(f, "'{}'에 숫자가 아닌 글자 '{}'이(가) 있습니다.", text, found),
+            CodeError::WrongLength(text) => {
+                write!(f, "{}은(는) {}자리입니다. {}자리 숫자를 입력해주세요.", text, text.len(), DIGITS)
+            }
+            CodeError::RepeatedDigit { text, digit } => {
+                write!(f, "{}에 {}이(가) 두 번 이상 나옵니다. 서로 다른 숫자를 입력해주세요.", text, digit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CodeError {}
+
+/// 숫자 야구 한 판의 상태. 설정에서는 최대 시도 횟수와 디버그 여부만 사용한다.
+#[derive(Debug)]
+pub struct BullsGame {
+    secret: Code,
+    config: Config,
+    attempts: u32,
+    last: Option<Feedback>,
+}
+
+impl BullsGame {
+    pub fn with_config(secret: Code, config: Config) -> BullsGame {
+        BullsGame { secret, config, attempts: 0, last: None }
+    }
+
+    pub fn with_rng<R: Rng>(rng: &mut R, config: Config) -> BullsGame {
+        BullsGame::with_config(Code::random(rng), config)
+    }
+
+    pub fn secret(&self) -> Code {
+        self.secret
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn remaining(&self) -> Option<u32> {
+        self.config.max_attempts.map(|max| max.saturating_sub(self.attempts))
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.last.is_some_and(|feedback| feedback.is_correct())
+    }
+
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && self.remaining() == Some(0)
+    }
+
+    /// 추측 하나를 처리하고 대답을 돌려준다.
+    pub fn guess(&mut self, guess: &Code) -> Feedback {
+        let feedback = self.secret.compare(guess);
+        self.attempts += 1;
+        self.last = Some(feedback);
+        feedback
+    }
+
+    /// Game::play와 같은 입력 루프. 잘못된 입력은 이유를 알려주고 다시 읽는다.
+    /// 기회와 게임이 끝날 때의 안내는 Game과 같은 문장을 쓴다.
+    pub fn play<R: BufRead, W: Write>(&mut self, mut input: R, mut output: W) -> io::Result<Outcome> {
+        writeln!(output, "서로 다른 숫자 {}개로 이루어진 {}자리 수를 맞춰보세요.", DIGITS, DIGITS)?;
+        write_max_attempts(&mut output, self.config.max_attempts)?;
+        if self.config.debug {
+            writeln!(output, "[디버그] 비밀 숫자는 {} 입니다.", self.secret)?;
+        }
+        loop {
+            let guess = match Prompt::new(&mut input, &mut output).ask_with("숫자를 입력하세여", Code::parse) {
+                Ok(guess) => guess,
+                Err(PromptError::Eof) => {
+                    writeln!(output, "{}", END_OF_INPUT)?;
+                    return Ok(Outcome::Quit);
+                }
+                Err(PromptError::Io(error)) => return Err(error),
+            };
+            let feedback = self.guess(&guess);
+            if feedback.is_correct() {
+                writeln!(output, "님이 맞춤 ({}번 만에)", self.attempts)?;
+                return Ok(Outcome::Won(self.attempts));
+            }
+            writeln!(output, "{}: {}", guess, feedback)?;
+            if write_remaining(&mut output, self.remaining(), self.secret)? {
+                return Ok(Outcome::Lost);
+            }
+        }
+    }
+}
+
+/// 풀이가 던진 추측 하나와 대답, 대답을 받은 뒤 남은 후보 수
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub guess: Code,
+    pub feedback: Feedback,
+    pub before: usize,
+    pub after: usize,
+}
+
+/// 지금까지의 대답과 모순되지 않는 후보들 가운데 첫 번째를 계속 추측한다.
+/// 후보를 남기는 기준은 "이 후보가 비밀 숫자였다면 같은 대답이 나왔을까" 이다.
+pub fn solve(game: &mut BullsGame) -> Vec<Step> {
+    let mut candidates = Code::all();
+    let mut steps = Vec::new();
+    while !game.is_won() && !game.is_lost() {
+        let guess = match candidates.first() {
+            Some(&guess) => guess,
+            None => break,
+        };
+        let feedback = game.guess(&guess);
+        let before = candidates.len();
+        candidates.retain(|candidate| candidate.compare(&guess) == feedback);
+        steps.push(Step { guess, feedback, before, after: candidates.len() });
+    }
+    steps
+}
+
+/// 컴퓨터가 숫자 야구를 풀면서 추측마다 후보가 얼마나 줄었는지 출력한다.
+pub fn play_solver<W: Write>(game: &mut BullsGame, mut output: W) -> io::Result<Outcome> {
+    writeln!(output, "컴퓨터가 서로 다른 숫자 {}개로 이루어진 {}자리 수를 맞춰봅니다.", DIGITS, DIGITS)?;
+    for (i, step) in solve(game).iter().enumerate() {
+        writeln!(
+            output,
+            "{:>2}번째 추측: {} -> {} (남은 후보 {} -> {})",
+            i + 1,
+            step.guess,
+            step.feedback,
+            step.before,
+            step.after
+        )?;
+    }
+    if game.is_won() {
+        writeln!(output, "{}번 만에 맞췄습니다.", game.attempts())?;
+        Ok(Outcome::Won(game.attempts()))
+    } else {
+        writeln!(output, "기회가 모자라 맞추지 못했습니다. 정답은 {} 였습니다.", game.secret())?;
+        Ok(Outcome::Lost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::seeded_rng;
+
+    fn code(text: &str) -> Code {
+        Code::parse(text).unwrap()
+    }
+
+    #[test]
+    fn parse_validates_format() {
+        assert_eq!(code(" 0123\n").to_string(), "0123");
+        assert_eq!(Code::parse(" \n"), Err(CodeError::Empty));
+        assert_eq!(Code::parse("12a4"), Err(CodeError::NotADigit { text: "12a4".to_string(), found: 'a' }));
+        assert_eq!(Code::parse("-123"), Err(CodeError::NotADigit { text: "-123".to_string(), found: '-' }));
+        assert_eq!(Code::parse("123"), Err(CodeError::WrongLength("123".to_string())));
+        assert_eq!(Code::parse("12345"), Err(CodeError::WrongLength("12345".to_string())));
+        assert_eq!(Code::parse("1231"), Err(CodeError::RepeatedDigit { text: "1231".to_string(), digit: 1 }));
+    }
+
+    #[test]
+    fn bulls_and_cows() {
+        let secret = code("1234");
+        assert_eq!(secret.compare(&code("1234")), Feedback { bulls: 4, cows: 0 });
+        assert_eq!(secret.compare(&code("1243")), Feedback { bulls: 2, cows: 2 });
+        assert_eq!(secret.compare(&code("4321")), Feedback { bulls: 0, cows: 4 });
+        assert_eq!(secret.compare(&code("1567")), Feedback { bulls: 1, cows: 0 });
+        assert_eq!(secret.compare(&code("5678")), Feedback { bulls: 0, cows: 0 });
+        assert_eq!(code("5678").compare(&secret), secret.compare(&code("5678")));
+    }
+
+    #[test]
+    fn random_codes_are_valid() {
+        let mut rng = seeded_rng(7);
+        for _ in 0..1000 {
+            let secret = Code::random(&mut rng);
+            assert_eq!(Code::parse(&secret.to_string()), Ok(secret));
+        }
+        assert_eq!(Code::all().len(), 5040);
+    }
+
+    #[test]
+    fn play_rejects_bad_guesses_without_counting_them() {
+        let mut game = BullsGame::with_config(code("1234"), Config::default());
+        let mut output = Vec::new();
+        let outcome = game.play(&b"1123\n12\n1243\n1234\n"[..], &mut output).unwrap();
+        assert_eq!(outcome, Outcome::Won(2));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("1123에 1이(가) 두 번 이상 나옵니다."));
+        assert!(output.contains("12은(는) 2자리입니다."));
+        assert!(output.contains("1243: 2 스트라이크 2 볼\n"));
+        assert!(output.ends_with("님이 맞춤 (2번 만에)\n"));
+    }
+
+    #[test]
+    fn play_ends_when_attempts_run_out() {
+        let config = Config { max_attempts: Some(1), ..Config::default() };
+        let mut game = BullsGame::with_config(code("1234"), config);
+        let mut output = Vec::new();
+        assert_eq!(game.play(&b"5678\n"[..], &mut output).unwrap(), Outcome::Lost);
+        assert!(String::from_utf8(output).unwrap().contains("5678: 아웃 (맞는 숫자가 하나도 없음)\n"));
+    }
+
+    #[test]
+    fn solver_shrinks_candidates_and_finds_every_secret() {
+        // 5040개를 모두 풀면 디버그 빌드에서 오래 걸리므로 골고루 뽑아서 확인한다.
+        for secret in Code::all().into_iter().step_by(37) {
+            let mut game = BullsGame::with_config(secret, Config::default());
+            let steps = solve(&mut game);
+            assert!(game.is_won());
+            assert!(steps.len() <= 10, "{}을(를) {}번 만에 찾음", secret, steps.len());
+            assert_eq!(steps[0].before, 5040);
+            for pair in steps.windows(2) {
+                assert_eq!(pair[1].before, pair[0].after);
+                // 틀린 추측은 자기 자신도 후보에서 지우므로 후보가 반드시 줄어든다.
+                assert!(pair[0].after < pair[0].before);
+            }
+            assert_eq!(steps.last().unwrap().after, 1);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::fmt;
+use std::io::{self, BufRead, Read};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// TimedInput이 입력을 기다리면서 마감 시각을 다시 확인하는 간격
+const POLL: Duration = Duration::from_millis(20);
+
+/// 게임이 시간을 재는 데 쓰는 시계. 어느 기준 시점부터 지난 시간을 돌려준다.
+///
+/// 게임은 두 시점의 차이만 쓰므로 기준 시점은 아무래도 상관없다.
+/// 테스트에서는 ManualClock을 넣어서 잠들지 않고도 제한 시간이 지난 상황을 만든다.
+pub trait Clock: fmt::Debug + Send {
+    fn now(&self) -> Duration;
+}
+
+/// 실제 시간을 쓰는 시계. 만든 순간이 기준 시점이다.
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        SystemClock { origin: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// advance를 부를 때만 시간이 흐르는 시계. 복제한 시계끼리 같은 시간을 공유하므로
+/// 하나는 게임에 넣고 다른 하나로 테스트에서 시간을 움직이면 된다.
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    now: Arc<Mutex<Duration>>,
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock::default()
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().expect("시계 잠금 실패") += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().expect("시계 잠금 실패")
+    }
+}
+
+/// 입력을 기다리다 멈출 시각 (시계 기준). 게임이 차례마다 정하고 TimedInput이 읽는다.
+/// 복제한 값끼리 같은 시각을 공유한다.
+#[derive(Debug, Clone, Default)]
+pub struct Deadline(Arc<Mutex<Option<Duration>>>);
+
+impl Deadline {
+    pub fn new() -> Deadline {
+        Deadline::default()
+    }
+
+    /// None이면 마감 없이 기다린다.
+    pub fn set(&self, at: Option<Duration>) {
+        *self.0.lock().expect("마감 시각 잠금 실패") = at;
+    }
+
+    pub fn get(&self) -> Option<Duration> {
+        *self.0.lock().expect("마감 시각 잠금 실패")
+    }
+}
+
+/// 입력을 기다리는 동안에도 마감 시각을 지켜보는 입력.
+///
+/// 보통의 read_line은 줄이 들어올 때까지 돌아오지 않아서 그동안 제한 시간이 지나도 알 수 없다.
+/// 그래서 다른 스레드에서 한 줄씩 읽어 채널로 넘겨받고, 기다리는 동안 시계가 마감 시각을 넘기면
+/// ErrorKind::TimedOut 에러를 돌려준다. 게임은 이 에러를 시간 초과로 처리한다. (Game::turn)
+pub struct TimedInput<C: Clock> {
+    lines: Receiver<io::Result<Vec<u8>>>,
+    clock: C,
+    deadline: Deadline,
+    buffer: Vec<u8>,
+    position: usize,
+    closed: bool,
+}
+
+impl<C: Clock> TimedInput<C> {
+    /// input을 읽을 스레드를 띄운다. 그 스레드는 입력이 끝날 때까지, 또는 이 값을 버린 뒤 다음 줄이 들어올 때까지 돈다.
+    pub fn new<R: BufRead + Send + 'static>(mut input: R, clock: C, deadline: Deadline) -> TimedInput<C> {
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || loop {
+            let mut line = Vec::new();
+            match input.read_until(b'\n', &mut line) {
+                Ok(0) => return,
+                Ok(_) => {
+                    if sender.send(Ok(line)).is_err() {
+                        return;
+                    }
+                }
+                Err(error) => {
+                    let _ = sender.send(Err(error));
+                    return;
+                }
+            }
+        });
+        TimedInput { lines, clock, deadline, buffer: Vec::new(), position: 0, closed: false }
+    }
+}
+
+impl<C: Clock> Read for TimedInput<C> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.fill_buf()?.read(buf)?;
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<C: Clock> BufRead for TimedInput<C> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        while self.position == self.buffer.len() && !self.closed {
+            if let Some(deadline) = self.deadline.get() {
+                if self.clock.now() > deadline {
+                    return Err(io::Error::new(io::ErrorKind::TimedOut, "입력을 기다리는 동안 제한 시간이 지났습니다."));
+                }
+            }
+            match self.lines.recv_timeout(POLL) {
+                Ok(line) => {
+                    self.buffer = line?;
+                    self.position = 0;
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => self.closed = true,
+            }
+        }
+        Ok(&self.buffer[self.position..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.position += amt;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manual_clock_is_shared_between_clones() {
+        let clock = ManualClock::new();
+        let copy = clock.clone();
+        assert_eq!(copy.now(), Duration::from_secs(0));
+        clock.advance(Duration::from_millis(1500));
+        assert_eq!(copy.now(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn system_clock_moves_forward() {
+        let clock = SystemClock::new();
+        let before = clock.now();
+        assert!(clock.now() >= before);
+    }
+
+    #[test]
+    fn timed_input_passes_lines_through() {
+        let mut input = TimedInput::new(&b"50\n42\n"[..], ManualClock::new(), Deadline::new());
+        let mut text = String::new();
+        input.read_to_string(&mut text).unwrap();
+        assert_eq!(text, "50\n42\n");
+    }
+}
@@ -0,0 +1,514 @@
+use std::ffi::OsStr;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::history::DEFAULT_HISTORY_FILE;
+use crate::scores::{DEFAULT_NAME, DEFAULT_SCORE_FILE};
+
+/// 범위와 시도 횟수를 한 번에 정해주는 난이도 프리셋.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    /// 설정의 범위와 시도 횟수가 어떤 프리셋과 정확히 같으면 그 난이도를 돌려준다.
+    pub fn of(config: &Config) -> Option<Difficulty> {
+        [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard]
+            .iter()
+            .cloned()
+            .find(|&difficulty| {
+                let preset = Config::preset(difficulty);
+                (preset.min, preset.max, preset.max_attempts) == (config.min, config.max, config.max_attempts)
+            })
+    }
+}
+
+/// 프로그램이 무엇을 할지
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    /// 게임을 한 판 한다.
+    Play,
+    /// 점수 파일에서 난이도별 상위 기록을 보여준다.
+    Scores { top: usize },
+    /// 기록 파일에서 지금까지 끝난 게임들의 통계를 보여준다.
+    Stats,
+    /// 컴퓨터가 이분 탐색으로 게임을 푼다.
+    Solve,
+    /// 기본 제공 전략들을 같은 비밀 숫자 games개로 겨루게 한다.
+    Tournament { games: usize },
+    /// 역할을 바꿔서 플레이어가 생각한 숫자를 컴퓨터가 맞춘다.
+    Reverse,
+    /// 재생 파일에 기록된 게임을 다시 돌리고 원래 출력과 비교한다.
+    Replay { path: PathBuf },
+    /// 여러 플레이어가 돌아가며 같은 숫자를 맞춘다. setter가 있으면 그 사람이 비밀 숫자를 정한다.
+    Multiplayer { players: Vec<String>, setter: Option<String> },
+    /// 숫자 야구. solve가 참이면 컴퓨터가 풀면서 후보가 줄어드는 과정을 보여준다.
+    Bulls { solve: bool },
+    /// 단어 맞추기. list가 없으면 내장 단어 목록을 쓴다.
+    Words { list: Option<PathBuf> },
+    /// TCP 서버를 열고 접속한 클라이언트마다 게임을 따로 진행한다.
+    Server { addr: String },
+}
+
+impl FromStr for Difficulty {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Difficulty, ConfigError> {
+        match s {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(ConfigError::UnknownDifficulty(s.to_string())),
+        }
+    }
+}
+
+/// 게임 한 판의 설정. 비밀 숫자는 min 이상 max 이하에서 뽑는다.
+/// max_attempts가 None이면 맞출 때까지 무제한으로 진행한다.
+/// debug가 켜져 있으면 비밀 숫자와 남은 후보 구간 같은 내부 상태를 같이 출력한다. (개발용)
+/// hints가 켜져 있으면 추측이 얼마나 가까운지 알려주고 한 번 쓸 수 있는 힌트를 준다.
+/// 이긴 게임은 name으로 score_file에 기록되고, 끝난 게임은 이기든 지든 history_file에 기록된다.
+/// seed가 있으면 비밀 숫자가 항상 같게 정해지고, record가 있으면 그 경로에 재생 파일을 남긴다.
+/// log가 있으면 그 경로에 JSON Lines 이벤트 기록을 남긴다.
+/// guess_time은 추측 한 번, time_limit은 게임 전체에 주어진 시간이고 넘기면 게임이 끝난다.
+/// speedrun이 켜져 있으면 추측마다 지난 시간을 보여준다.
+/// tui가 켜져 있으면 터미널에서 전체 화면 모드로 게임을 한다.
+/// script가 있으면 그 파일(-이면 표준 입력)에서 추측을 읽어 안내 없이 한 판을 하고 결과를 종료 코드로 알린다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub mode: Mode,
+    pub min: u32,
+    pub max: u32,
+    pub max_attempts: Option<u32>,
+    pub debug: bool,
+    pub hints: bool,
+    pub name: String,
+    pub score_file: PathBuf,
+    pub history_file: PathBuf,
+    pub seed: Option<u32>,
+    pub record: Option<PathBuf>,
+    pub log: Option<PathBuf>,
+    pub guess_time: Option<Duration>,
+    pub time_limit: Option<Duration>,
+    pub speedrun: bool,
+    pub tui: bool,
+    pub script: Option<PathBuf>,
+}
+
+impl Default for Config {
+    /// 원래 게임과 같은 설정 (1 ~ 100, 횟수 제한 없음)
+    fn default() -> Config {
+        Config {
+            mode: Mode::Play,
+            min: 1,
+            max: 100,
+            max_attempts: None,
+            debug: false,
+            hints: false,
+            name: DEFAULT_NAME.to_string(),
+            score_file: PathBuf::from(DEFAULT_SCORE_FILE),
+            history_file: PathBuf::from(DEFAULT_HISTORY_FILE),
+            seed: None,
+            record: None,
+            log: None,
+            guess_time: None,
+            time_limit: None,
+            speedrun: false,
+            tui: false,
+            script: None,
+        }
+    }
+}
+
+impl Config {
+    pub fn preset(difficulty: Difficulty) -> Config {
+        let (max, max_attempts) = match difficulty {
+            Difficulty::Easy => (50, 10),
+            Difficulty::Normal => (100, 7),
+            Difficulty::Hard => (1000, 10),
+        };
+        Config {
+            min: 1,
+            max,
+            max_attempts: Some(max_attempts),
+            ..Config::default()
+        }
+    }
+
+    /// 명령줄 인수(프로그램 이름 제외)로부터 설정을 만든다.
+    /// --difficulty로 프리셋을 고른 뒤 --min, --max, --attempts로 개별 값을 덮어쓸 수 있다.
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Config, ConfigError> {
+        let mut difficulty = None;
+        let mut min = None;
+        let mut max = None;
+        let mut max_attempts = None;
+        let mut debug = false;
+        let mut hints = false;
+        let mut name = None;
+        let mut score_file = None;
+        let mut history_file = None;
+        let mut stats = false;
+        let mut scores = false;
+        let mut top = 10;
+        let mut solve = false;
+        let mut tournament = false;
+        let mut games = 10_000;
+        let mut reverse = false;
+        let mut seed = None;
+        let mut record = None;
+        let mut log = None;
+        let mut guess_time = None;
+        let mut time_limit = None;
+        let mut speedrun = false;
+        let mut tui = false;
+        let mut script = None;
+        let mut replay = None;
+        let mut players = None;
+        let mut setter = None;
+        let mut server = None;
+        let mut bulls = false;
+        let mut words = false;
+        let mut word_list = None;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--difficulty" | "-d" => difficulty = Some(value(&arg, args.next())?.parse()?),
+                "--min" => min = Some(number(&arg, args.next())?),
+                "--max" => max = Some(number(&arg, args.next())?),
+                "--attempts" | "-n" => max_attempts = Some(number(&arg, args.next())?),
+                "--debug" => debug = true,
+                "--hints" => hints = true,
+                "--name" => name = Some(value(&arg, args.next())?),
+                "--score-file" => score_file = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--history-file" => history_file = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--scores" => scores = true,
+                "stats" => stats = true,
+                "--top" => top = number(&arg, args.next())? as usize,
+                "--solve" => solve = true,
+                "--tournament" => tournament = true,
+                "--games" => games = number(&arg, args.next())? as usize,
+                "--reverse" => reverse = true,
+                "--seed" => seed = Some(number(&arg, args.next())?),
+                "--record" => record = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--guess-time" => guess_time = Some(seconds(&arg, args.next())?),
+                "--time-limit" => time_limit = Some(seconds(&arg, args.next())?),
+                "--speedrun" => speedrun = true,
+                "--tui" => tui = true,
+                "--script" => script = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--log" => log = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--replay" => replay = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--players" => players = Some(value(&arg, args.next())?),
+                "--setter" => setter = Some(value(&arg, args.next())?),
+                "--bulls" => bulls = true,
+                "--words" => words = true,
+                "--word-list" => word_list = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--server" => server = Some(value(&arg, args.next())?),
+                _ => return Err(ConfigError::UnknownOption(arg)),
+            }
+        }
+
+        let mut config = match difficulty {
+            Some(difficulty) => Config::preset(difficulty),
+            None => Config::default(),
+        };
+        config.min = min.unwrap_or(config.min);
+        config.max = max.unwrap_or(config.max);
+        if max_attempts.is_some() {
+            config.max_attempts = max_attempts;
+        }
+        config.debug = debug;
+        config.hints = hints;
+        // 빈 이름으로는 점수를 남길 수 없으므로 기본 이름을 쓴다.
+        config.name = name.filter(|name| !name.trim().is_empty()).unwrap_or(config.name);
+        config.score_file = score_file.unwrap_or(config.score_file);
+        config.history_file = history_file.unwrap_or(config.history_file);
+        config.seed = seed;
+        config.record = record;
+        config.log = log;
+        config.guess_time = guess_time;
+        config.time_limit = time_limit;
+        config.speedrun = speedrun;
+        config.tui = tui;
+        // 게임 방식을 정하는 옵션은 하나만 쓸 수 있다. (--bulls --solve, --words --word-list, --players --setter는 한 방식)
+        // 스크립트 모드도 기본 게임 방식 중 하나이므로 같이 센다.
+        let modes = [
+            ("stats", stats),
+            ("--scores", scores),
+            ("--bulls", bulls),
+            (if words { "--words" } else { "--word-list" }, words || word_list.is_some()),
+            ("--solve", solve && !bulls),
+            ("--tournament", tournament),
+            ("--reverse", reverse),
+            ("--replay", replay.is_some()),
+            ("--server", server.is_some()),
+            (if players.is_some() { "--players" } else { "--setter" }, players.is_some() || setter.is_some()),
+            ("--script", script.is_some()),
+        ];
+        let mut given = modes.iter().filter(|&&(_, on)| on).map(|&(flag, _)| flag);
+        if let (Some(first), Some(second)) = (given.next(), given.next()) {
+            return Err(ConfigError::Conflict(first, second));
+        }
+        config.script = script;
+        if stats {
+            config.mode = Mode::Stats;
+        } else if scores {
+            config.mode = Mode::Scores { top };
+        } else if bulls {
+            config.mode = Mode::Bulls { solve };
+        } else if words || word_list.is_some() {
+            config.mode = Mode::Words { list: word_list };
+        } else if solve {
+            config.mode = Mode::Solve;
+        } else if tournament {
+            config.mode = Mode::Tournament { games };
+        } else if reverse {
+            config.mode = Mode::Reverse;
+        } else if let Some(path) = replay {
+            config.mode = Mode::Replay { path };
+        } else if let Some(addr) = server {
+            config.mode = Mode::Server { addr };
+        } else if players.is_some() || setter.is_some() {
+            // 출제자는 맞추는 사람에서 빠진다.
+            let players: Vec<String> = players
+                .as_deref()
+                .unwrap_or("")
+                .split(',')
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty() && Some(name) != setter.as_ref())
+                .collect();
+            let needed = if setter.is_some() { 1 } else { 2 };
+            if players.len() < needed {
+                return Err(ConfigError::NotEnoughPlayers { needed, got: players.len() });
+            }
+            config.mode = Mode::Multiplayer { players, setter };
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// 범위가 비어 있거나 시도 횟수가 0인 설정을 거부한다.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.min >= self.max {
+            return Err(ConfigError::EmptyRange { min: self.min, max: self.max });
+        }
+        if self.max_attempts == Some(0) {
+            return Err(ConfigError::ZeroAttempts);
+        }
+        // 재생 파일은 Game::play의 입출력을 그대로 남기는데 스크립트 모드는 안내를 출력하지 않아서 같이 쓸 수 없다.
+        if self.script.is_some() && self.record.is_some() {
+            return Err(ConfigError::Conflict("--script", "--record"));
+        }
+        // 재생(--replay)은 시계 없이 입력만 다시 넣으므로 시간에 따라 달라지는 게임은 기록해도 똑같이 재생되지 않는다.
+        if self.record.is_some() {
+            if self.guess_time.is_some() {
+                return Err(ConfigError::Conflict("--guess-time", "--record"));
+            }
+            if self.time_limit.is_some() {
+                return Err(ConfigError::Conflict("--time-limit", "--record"));
+            }
+            if self.speedrun {
+                return Err(ConfigError::Conflict("--speedrun", "--record"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// GUESSING_GAME_DEBUG처럼 켜고 끄는 환경 변수의 값을 해석한다.
+/// 변수가 없거나, 값이 비어 있거나, "0"이면 꺼진 것으로 본다.
+pub fn env_flag(value: Option<&OsStr>) -> bool {
+    match value {
+        Some(value) => !value.is_empty() && value != "0",
+        None => false,
+    }
+}
+
+fn value(option: &str, value: Option<String>) -> Result<String, ConfigError> {
+    value.ok_or_else(|| ConfigError::MissingValue(option.to_string()))
+}
+
+fn number(option: &str, arg: Option<String>) -> Result<u32, ConfigError> {
+    let arg = value(option, arg)?;
+    arg.parse().map_err(|_| ConfigError::InvalidNumber {
+        option: option.to_string(),
+        value: arg,
+    })
+}
+
+/// 0보다 큰 초 단위 시간. 소수도 받는다. (예: 2.5)
+fn seconds(option: &str, arg: Option<String>) -> Result<Duration, ConfigError> {
+    let arg = value(option, arg)?;
+    match arg.parse::<f64>() {
+        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => Ok(Duration::from_secs_f64(seconds)),
+        _ => Err(ConfigError::InvalidNumber { option: option.to_string(), value: arg }),
+    }
+}
+
+/// 잘못된 명령줄 인수나 설정 조합
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    UnknownOption(String),
+    MissingValue(String),
+    InvalidNumber { option: String, value: String },
+    UnknownDifficulty(String),
+    EmptyRange { min: u32, max: u32 },
+    ZeroAttempts,
+    NotEnoughPlayers { needed: usize, got: usize },
+    /// 같이 쓸 수 없는 두 옵션
+    Conflict(&'static str, &'static str),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::UnknownOption(option) => write!(f, "알 수 없는 옵션입니다: {}", option),
+            ConfigError::MissingValue(option) => write!(f, "{} 옵션에 값이 없습니다.", option),
+            ConfigError::InvalidNumber { option, value } => {
+                write!(f, "{} 옵션의 값 '{}'은(는) 올바른 숫자가 아닙니다.", option, value)
+            }
+            ConfigError::UnknownDifficulty(name) => {
+                write!(f, "알 수 없는 난이도입니다: {} (easy, normal, hard 중 하나)", name)
+            }
+            ConfigError::EmptyRange { min, max } => {
+                write!(f, "최솟값({})은 최댓값({})보다 작아야 합니다.", min, max)
+            }
+            ConfigError::ZeroAttempts => write!(f, "시도 횟수는 1 이상이어야 합니다."),
+            ConfigError::NotEnoughPlayers { needed, got } => {
+                write!(f, "맞추는 플레이어가 {}명 이상 필요합니다. (지금 {}명)", needed, got)
+            }
+            ConfigError::Conflict(first, second) => write!(f, "{} 옵션과 {} 옵션은 같이 쓸 수 없습니다.", first, second),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Config, ConfigError> {
+        Config::from_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn no_args_is_default() {
+        assert_eq!(parse(&[]), Ok(Config::default()));
+    }
+
+    #[test]
+    fn options_override_preset() {
+        let config = parse(&["--difficulty", "hard", "--attempts", "3"]).unwrap();
+        assert_eq!((config.min, config.max, config.max_attempts), (1, 1000, Some(3)));
+        assert_eq!(Difficulty::of(&config), None);
+        assert_eq!(Difficulty::of(&parse(&["-d", "easy"]).unwrap()), Some(Difficulty::Easy));
+    }
+
+    #[test]
+    fn debug_flag() {
+        assert!(!parse(&[]).unwrap().debug);
+        assert!(parse(&["--debug"]).unwrap().debug);
+        assert!(parse(&["--hints"]).unwrap().hints);
+    }
+
+    #[test]
+    fn debug_env_flag() {
+        assert!(!env_flag(None));
+        assert!(!env_flag(Some(OsStr::new(""))));
+        assert!(!env_flag(Some(OsStr::new("0"))));
+        assert!(env_flag(Some(OsStr::new("1"))));
+        assert!(env_flag(Some(OsStr::new("yes"))));
+    }
+
+    #[test]
+    fn seed_and_record() {
+        let config = parse(&["--seed", "42", "--record", "game.replay"]).unwrap();
+        assert_eq!(config.seed, Some(42));
+        assert_eq!(config.record, Some(PathBuf::from("game.replay")));
+        let config = parse(&["--guess-time", "2.5", "--time-limit", "60", "--speedrun"]).unwrap();
+        assert_eq!(config.guess_time, Some(Duration::from_millis(2500)));
+        assert_eq!(config.time_limit, Some(Duration::from_secs(60)));
+        assert!(config.speedrun);
+        assert!(parse(&["--tui"]).unwrap().tui);
+        assert_eq!(parse(&["--script", "-"]).unwrap().script, Some(PathBuf::from("-")));
+        assert!(parse(&["--guess-time", "0"]).is_err());
+        assert!(parse(&["--time-limit", "-1"]).is_err());
+        assert_eq!(parse(&["--log", "game.jsonl"]).unwrap().log, Some(PathBuf::from("game.jsonl")));
+        assert!(parse(&["--seed", "-1"]).is_err());
+    }
+
+    #[test]
+    fn scores_mode() {
+        assert_eq!(parse(&[]).unwrap().mode, Mode::Play);
+        assert_eq!(parse(&["--top", "3", "--scores"]).unwrap().mode, Mode::Scores { top: 3 });
+        assert_eq!(parse(&["--solve", "-d", "hard"]).unwrap().mode, Mode::Solve);
+        assert_eq!(parse(&["--tournament", "--games", "50"]).unwrap().mode, Mode::Tournament { games: 50 });
+        assert_eq!(parse(&["--reverse"]).unwrap().mode, Mode::Reverse);
+        let config = parse(&["stats", "--history-file", "h.txt"]).unwrap();
+        assert_eq!((config.mode, config.history_file), (Mode::Stats, PathBuf::from("h.txt")));
+        assert_eq!(parse(&["--bulls"]).unwrap().mode, Mode::Bulls { solve: false });
+        assert_eq!(parse(&["--solve", "--bulls"]).unwrap().mode, Mode::Bulls { solve: true });
+        assert_eq!(parse(&["--words"]).unwrap().mode, Mode::Words { list: None });
+        assert_eq!(parse(&["--word-list", "w.txt"]).unwrap().mode, Mode::Words { list: Some(PathBuf::from("w.txt")) });
+        assert_eq!(parse(&["--server", "0.0.0.0:9000"]).unwrap().mode, Mode::Server { addr: "0.0.0.0:9000".to_string() });
+        assert_eq!(parse(&["--replay", "a.txt"]).unwrap().mode, Mode::Replay { path: PathBuf::from("a.txt") });
+    }
+
+    #[test]
+    fn only_one_mode_at_a_time() {
+        assert_eq!(parse(&["--script", "-", "--solve"]), Err(ConfigError::Conflict("--solve", "--script")));
+        assert_eq!(parse(&["--scores", "--solve"]), Err(ConfigError::Conflict("--scores", "--solve")));
+        assert_eq!(parse(&["--bulls", "--words"]), Err(ConfigError::Conflict("--bulls", "--words")));
+        assert_eq!(parse(&["--reverse", "--word-list", "w.txt"]), Err(ConfigError::Conflict("--word-list", "--reverse")));
+        assert_eq!(parse(&["stats", "--setter", "철수", "--players", "영희"]), Err(ConfigError::Conflict("stats", "--players")));
+        assert!(parse(&["--words", "--word-list", "w.txt"]).is_ok());
+    }
+
+    #[test]
+    fn empty_name_is_anonymous() {
+        assert_eq!(parse(&["--name", "철수"]).unwrap().name, "철수");
+        assert_eq!(parse(&["--name", ""]).unwrap().name, DEFAULT_NAME);
+        assert_eq!(parse(&["--name", "  "]).unwrap().name, DEFAULT_NAME);
+    }
+
+    #[test]
+    fn multiplayer_mode() {
+        let players = |names: &[&str]| names.iter().map(|s| s.to_string()).collect::<Vec<String>>();
+        assert_eq!(
+            parse(&["--players", "철수, 영희,,"]).unwrap().mode,
+            Mode::Multiplayer { players: players(&["철수", "영희"]), setter: None }
+        );
+        assert_eq!(
+            parse(&["--players", "철수,영희", "--setter", "철수"]).unwrap().mode,
+            Mode::Multiplayer { players: players(&["영희"]), setter: Some("철수".to_string()) }
+        );
+        assert_eq!(parse(&["--players", "철수"]), Err(ConfigError::NotEnoughPlayers { needed: 2, got: 1 }));
+        assert_eq!(parse(&["--setter", "철수"]), Err(ConfigError::NotEnoughPlayers { needed: 1, got: 0 }));
+    }
+
+    #[test]
+    fn invalid_combinations_are_rejected() {
+        assert_eq!(parse(&["--min", "10", "--max", "10"]), Err(ConfigError::EmptyRange { min: 10, max: 10 }));
+        assert_eq!(parse(&["--attempts", "0"]), Err(ConfigError::ZeroAttempts));
+        assert_eq!(parse(&["--max"]), Err(ConfigError::MissingValue("--max".to_string())));
+        assert_eq!(parse(&["-d", "insane"]), Err(ConfigError::UnknownDifficulty("insane".to_string())));
+        assert_eq!(parse(&["--script", "a.txt", "--record", "b.txt"]), Err(ConfigError::Conflict("--script", "--record")));
+        assert_eq!(parse(&["--record", "b.txt", "--guess-time", "5"]), Err(ConfigError::Conflict("--guess-time", "--record")));
+        assert_eq!(parse(&["--time-limit", "60", "--record", "b.txt"]), Err(ConfigError::Conflict("--time-limit", "--record")));
+        assert_eq!(parse(&["--speedrun", "--record", "b.txt"]), Err(ConfigError::Conflict("--speedrun", "--record")));
+    }
+}
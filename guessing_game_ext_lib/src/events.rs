@@ -0,0 +1,153 @@
+/*
+게임 한 판에서 일어난 일을 JSON Lines(한 줄에 JSON 객체 하나)로 남기는 구조화된 기록.
+
+재생 파일(replay.rs)은 게임을 똑같이 다시 돌리기 위한 것이고, 이 기록은 밖에서 분석하기 위한 것이다.
+필드 이름과 순서, 값의 모양은 --help에 적어둔 스키마와 같아야 하므로 바꿀 때는 SCHEMA_VERSION을 올린다.
+외부 crate 없이 직접 JSON을 쓴다. 문자열 말고는 숫자, null, 고정된 영어 단어만 쓰기 때문이다.
+*/
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::game::Outcome;
+use crate::input::GuessError;
+
+/// 기록 형식의 버전. start 이벤트의 version 필드에 들어간다.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// 기록에 남기는 이벤트 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// 게임 시작. 시드를 모르면 null 이다.
+    Start { min: u32, max: u32, max_attempts: Option<u32>, seed: Option<u32> },
+    /// 플레이어가 입력한 줄 하나 (앞뒤 공백을 잘라냄). 추측으로 받아들였으면 Ok(값), 아니면 Err(거절한 이유) 이다.
+    Guess { input: String, result: Result<u32, GuessError> },
+    /// 받아들인 추측을 비밀 숫자와 비교한 결과. attempt는 이 추측까지의 시도 횟수이다.
+    Compare { attempt: u32, guess: u32, ordering: Ordering },
+    /// 한 번 쓸 수 있는 힌트를 썼다.
+    Hint { attempt: u32 },
+    /// 게임 끝
+    End { outcome: Outcome, attempts: u32, duration_ms: u64 },
+}
+
+impl Event {
+    /// 줄바꿈 없는 JSON 객체 한 줄로 바꾼다.
+    pub fn to_json(&self) -> String {
+        match self {
+            Event::Start { min, max, max_attempts, seed } => format!(
+                "{{\"event\":\"start\",\"version\":{},\"min\":{},\"max\":{},\"max_attempts\":{},\"seed\":{}}}",
+                SCHEMA_VERSION,
+                min,
+                max,
+                number_or_null(*max_attempts),
+                number_or_null(*seed)
+            ),
+            Event::Guess { input, result: Ok(value) } => {
+                format!("{{\"event\":\"guess\",\"input\":{},\"value\":{}}}", json_string(input), value)
+            }
+            Event::Guess { input, result: Err(error) } => format!(
+                "{{\"event\":\"guess\",\"input\":{},\"rejected\":\"{}\",\"message\":{}}}",
+                json_string(input),
+                error.code(),
+                json_string(&error.to_string())
+            ),
+            Event::Compare { attempt, guess, ordering } => {
+                let ordering = match ordering {
+                    Ordering::Less => "less",
+                    Ordering::Greater => "greater",
+                    Ordering::Equal => "equal",
+                };
+                format!("{{\"event\":\"compare\",\"attempt\":{},\"guess\":{},\"ordering\":\"{}\"}}", attempt, guess, ordering)
+            }
+            Event::Hint { attempt } => format!("{{\"event\":\"hint\",\"attempt\":{}}}", attempt),
+            Event::End { outcome, attempts, duration_ms } => {
+                let outcome = match outcome {
+                    Outcome::Won(_) => "won",
+                    Outcome::Lost => "lost",
+                    Outcome::Quit => "quit",
+                    Outcome::TimedOut => "timed_out",
+                };
+                format!(
+                    "{{\"event\":\"end\",\"outcome\":\"{}\",\"attempts\":{},\"duration_ms\":{}}}",
+                    outcome, attempts, duration_ms
+                )
+            }
+        }
+    }
+}
+
+fn number_or_null(value: Option<u32>) -> String {
+    value.map_or_else(|| "null".to_string(), |value| value.to_string())
+}
+
+/// 따옴표, 역슬래시, 제어 문자를 이스케이프한 JSON 문자열. 한글은 그대로 UTF-8로 쓴다.
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// 이벤트를 받아 한 줄씩 쓰는 곳.
+/// 게임이 스레드 사이를 옮겨 다닐 수 있도록(server.rs) Send인 출력만 받는다.
+pub struct EventLog {
+    output: Box<dyn Write + Send>,
+}
+
+impl EventLog {
+    pub fn new<W: Write + Send + 'static>(output: W) -> EventLog {
+        EventLog { output: Box::new(output) }
+    }
+
+    /// 이벤트 하나를 쓰고 바로 flush 한다. 게임이 도중에 죽어도 그때까지의 기록은 남는다.
+    pub fn write(&mut self, event: &Event) -> io::Result<()> {
+        writeln!(self.output, "{}", event.to_json())?;
+        self.output.flush()
+    }
+}
+
+impl fmt::Debug for EventLog {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EventLog").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_as_json() {
+        let start = Event::Start { min: 1, max: 100, max_attempts: None, seed: Some(7) };
+        assert_eq!(
+            start.to_json(),
+            "{\"event\":\"start\",\"version\":1,\"min\":1,\"max\":100,\"max_attempts\":null,\"seed\":7}"
+        );
+        let guess = Event::Guess { input: "42".to_string(), result: Ok(42) };
+        assert_eq!(guess.to_json(), "{\"event\":\"guess\",\"input\":\"42\",\"value\":42}");
+        let compare = Event::Compare { attempt: 2, guess: 42, ordering: Ordering::Less };
+        assert_eq!(compare.to_json(), "{\"event\":\"compare\",\"attempt\":2,\"guess\":42,\"ordering\":\"less\"}");
+        let end = Event::End { outcome: Outcome::Won(3), attempts: 3, duration_ms: 1500 };
+        assert_eq!(end.to_json(), "{\"event\":\"end\",\"outcome\":\"won\",\"attempts\":3,\"duration_ms\":1500}");
+    }
+
+    #[test]
+    fn rejected_input_is_escaped() {
+        let guess = Event::Guess { input: "a\"b\\".to_string(), result: Err(GuessError::NotANumber("a\"b\\".to_string())) };
+        assert_eq!(
+            guess.to_json(),
+            "{\"event\":\"guess\",\"input\":\"a\\\"b\\\\\",\"rejected\":\"not_a_number\",\"message\":\"'a\\\"b\\\\'은(는) 숫자가 아닙니다.\"}"
+        );
+        assert_eq!(json_string("\u{1}\t"), "\"\\u0001\\t\"");
+    }
+}
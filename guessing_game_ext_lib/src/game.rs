@@ -0,0 +1,799 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+use prompt::{Prompt, PromptError};
+use rand::{Rng, SeedableRng, XorShiftRng}; // 점수 생성기가 구현한 메소드들을 정의한 trait
+
+use crate::clock::{Clock, Deadline, SystemClock};
+use crate::config::Config;
+use crate::events::{Event, EventLog};
+use crate::hints::{is_hint_request, NumberHint, Temperature, Trend};
+use crate::input::parse_guess;
+
+/// 시드 하나로 항상 같은 순서의 난수를 만드는 생성기. 같은 시드면 같은 비밀 숫자가 나온다.
+pub fn seeded_rng(seed: u32) -> XorShiftRng {
+    // XorShiftRng는 시드가 모두 0이면 안 되므로 상수를 섞는다.
+    XorShiftRng::from_seed([seed, 0x193a_6754, 0xa8a7_d469, 0x9783_0e05])
+}
+
+/// 한 판의 게임이 어떻게 끝났는지 나타낸다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// 비밀 숫자를 맞췄다. 맞추기까지 걸린 시도 횟수를 가진다.
+    Won(u32),
+    /// 주어진 기회를 모두 썼다.
+    Lost,
+    /// 숫자를 맞추기 전에 입력이 끝났다. (EOF)
+    Quit,
+    /// 한 번의 추측이나 게임 전체에 주어진 시간을 넘겼다.
+    TimedOut,
+}
+
+/// 입력이 끝나서 게임을 그만둘 때의 안내. 숫자 야구(bulls.rs)와 단어 맞추기(words.rs)도 같은 문장을 쓴다.
+pub(crate) const END_OF_INPUT: &str = "입력이 끝나서 게임을 마칩니다.";
+
+/// 횟수 제한이 있으면 기회가 몇 번인지 알려준다.
+pub(crate) fn write_max_attempts<W: Write>(mut output: W, max_attempts: Option<u32>) -> io::Result<()> {
+    if let Some(max_attempts) = max_attempts {
+        writeln!(output, "기회는 {}번 입니다.", max_attempts)?;
+    }
+    Ok(())
+}
+
+/// 틀린 추측 뒤에 남은 기회를 알려준다. 기회를 모두 썼으면 정답을 알려주고 true를 돌려준다.
+pub(crate) fn write_remaining<W: Write, S: fmt::Display>(mut output: W, remaining: Option<u32>, secret: S) -> io::Result<bool> {
+    match remaining {
+        Some(0) => {
+            writeln!(output, "기회를 모두 썼습니다. 정답은 {} 였습니다.", secret)?;
+            Ok(true)
+        }
+        Some(remaining) => {
+            writeln!(output, "남은 기회: {}", remaining)?;
+            Ok(false)
+        }
+        None => Ok(false),
+    }
+}
+
+/// 숫자 맞추기 게임 한 판의 상태.
+///
+/// 비밀 숫자, 지금까지의 시도 횟수, 마지막 추측의 비교 결과를 가지고 있다.
+#[derive(Debug)]
+pub struct Game {
+    secret: u32,
+    config: Config,
+    attempts: u32,
+    last: Option<Ordering>,
+    last_guess: Option<u32>,
+    guesses: Vec<(u32, Ordering)>,
+    low: u32,
+    high: u32,
+    hint_used: bool,
+    log: Option<EventLog>,
+    clock: Box<dyn Clock>,
+    /// 첫 입력을 기다리기 시작한 시각, 이번 추측을 기다리기 시작한 시각, 게임이 끝난 시각 (clock 기준)
+    started: Option<Duration>,
+    turn_started: Option<Duration>,
+    ended: Option<Duration>,
+    /// 이번 추측을 언제까지 기다릴지. 입력 쪽(clock::TimedInput)과 공유한다.
+    deadline: Deadline,
+}
+
+/// 입력 한 줄을 처리한 결과 (Game::respond)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// 추측으로 받아들일 수 없는 입력이었다. 시도 횟수는 그대로이고 같은 차례에 다시 입력받는다.
+    Retry,
+    /// 시도 하나를 썼고 게임은 계속된다.
+    Next,
+    /// 게임이 끝났다.
+    Over(Outcome),
+}
+
+/// 입력 한 줄로 정해진, 이번 차례에 할 일 (Game::read_move)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Move {
+    /// 제한 시간이 지난 뒤에 들어온 입력. 처리하지 않고 게임을 끝낸다.
+    Late,
+    Hint,
+    Guess(u32),
+}
+
+/// 추측을 물을 때 출력하는 문장. 시간 제한이 있으면 출력할 때마다 남은 시간을 새로 계산해서 앞에 붙인다.
+struct Countdown<'a>(&'a Game);
+
+impl<'a> fmt::Display for Countdown<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(left) = self.0.guess_time_left() {
+            write!(f, "(이번 추측 남은 시간 {:.1}초) ", left.as_secs_f64())?;
+        }
+        if let Some(left) = self.0.time_left() {
+            write!(f, "(전체 남은 시간 {:.1}초) ", left.as_secs_f64())?;
+        }
+        write!(f, "숫자를 입력하세여")
+    }
+}
+
+impl Game {
+    /// 비밀 숫자를 직접 정해서 기본 설정(1 ~ 100, 횟수 제한 없음)의 게임을 만든다. 테스트에서 주로 사용한다.
+    pub fn new(secret: u32) -> Game {
+        Game::with_config(secret, Config::default())
+    }
+
+    pub fn with_config(secret: u32, config: Config) -> Game {
+        Game {
+            secret,
+            low: config.min,
+            high: config.max,
+            config,
+            attempts: 0,
+            last: None,
+            last_guess: None,
+            guesses: Vec::new(),
+            hint_used: false,
+            log: None,
+            clock: Box::new(SystemClock::new()),
+            started: None,
+            turn_started: None,
+            ended: None,
+            deadline: Deadline::new(),
+        }
+    }
+
+    /// 주입받은 난수 생성기로 설정의 범위(min 이상 max 이하) 안에서 비밀 숫자를 뽑아 게임을 만든다.
+    pub fn with_rng<R: Rng>(rng: &mut R, config: Config) -> Game {
+        // max가 u32::MAX일 때 max + 1이 넘치지 않도록 u64로 뽑는다.
+        let secret = rng.gen_range(u64::from(config.min), u64::from(config.max) + 1) as u32;
+        Game::with_config(secret, config)
+    }
+
+    /// play/turn에서 일어난 일을 JSON Lines 이벤트로 남긴다. 시작 이벤트의 시드는 config.seed 이다.
+    pub fn with_log(mut self, log: EventLog) -> Game {
+        self.log = Some(log);
+        self
+    }
+
+    /// 제한 시간과 걸린 시간을 잴 시계를 바꾼다. 기본값은 실제 시간(SystemClock)이다.
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Game {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// 첫 입력을 기다리기 시작한 뒤로 지난 시간. 게임이 끝났으면 끝날 때까지 걸린 시간이다.
+    pub fn elapsed(&self) -> Duration {
+        match self.started {
+            Some(started) => self.ended.unwrap_or_else(|| self.clock.now()) - started,
+            None => Duration::from_secs(0),
+        }
+    }
+
+    /// 제한 시간을 넘겼으면 그 이유를 돌려준다. 전체 제한 시간을 먼저 본다.
+    fn timeout(&self) -> Option<String> {
+        let now = self.clock.now();
+        if let Some(limit) = self.config.time_limit {
+            if self.elapsed() > limit {
+                return Some(format!("전체 제한 시간 {}초가 지났습니다.", limit.as_secs_f64()));
+            }
+        }
+        if let (Some(limit), Some(turn_started)) = (self.config.guess_time, self.turn_started) {
+            if now - turn_started > limit {
+                return Some(format!("한 번에 쓸 수 있는 시간 {}초가 지났습니다.", limit.as_secs_f64()));
+            }
+        }
+        None
+    }
+
+    /// 제한 시간을 넘겼다고 알리고 게임을 끝낸다.
+    pub(crate) fn time_up<W: Write>(&mut self, mut output: W) -> io::Result<Outcome> {
+        let reason = self.timeout().unwrap_or_else(|| "제한 시간이 지났습니다.".to_string());
+        writeln!(output, "시간 초과! {} 정답은 {} 였습니다.", reason, self.secret)?;
+        self.finish(Outcome::TimedOut)
+    }
+
+    /// 시간 제한이나 스피드런이 켜져 있으면 시간을 보여준다.
+    fn is_timed(&self) -> bool {
+        self.config.time_limit.is_some() || self.config.guess_time.is_some() || self.config.speedrun
+    }
+
+    fn log(&mut self, event: Event) -> io::Result<()> {
+        match self.log {
+            Some(ref mut log) => log.write(&event),
+            None => Ok(()),
+        }
+    }
+
+    /// 게임이 끝났음을 기록하고 결과를 그대로 돌려준다.
+    fn finish(&mut self, outcome: Outcome) -> io::Result<Outcome> {
+        let now = self.clock.now();
+        self.started.get_or_insert(now);
+        self.ended = Some(now);
+        self.turn_started = None;
+        self.deadline.set(None);
+        let duration_ms = self.elapsed().as_millis() as u64;
+        self.log(Event::End { outcome, attempts: self.attempts, duration_ms })?;
+        Ok(outcome)
+    }
+
+    pub fn secret(&self) -> u32 {
+        self.secret
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// 입력을 기다리다 멈출 시각. clock::TimedInput에 넘기면 입력을 기다리는 동안 제한 시간이 지났을 때
+    /// 줄이 들어오기를 기다리지 않고 게임이 끝난다. 시계는 with_clock으로 넣은 것과 같아야 한다.
+    pub fn deadline(&self) -> Deadline {
+        self.deadline.clone()
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// 남은 기회. 횟수 제한이 없으면 None이다.
+    pub fn remaining(&self) -> Option<u32> {
+        self.config.max_attempts.map(|max| max.saturating_sub(self.attempts))
+    }
+
+    /// 마지막 추측의 비교 결과. 아직 한 번도 추측하지 않았다면 None이다.
+    pub fn last(&self) -> Option<Ordering> {
+        self.last
+    }
+
+    /// 마지막으로 추측한 숫자
+    pub fn last_guess(&self) -> Option<u32> {
+        self.last_guess
+    }
+
+    /// 처음으로 추측한 숫자. 플레이어가 이분 탐색처럼 가운데부터 부르는지 보는 데 쓴다. (history.rs)
+    pub fn first_guess(&self) -> Option<u32> {
+        self.guesses.first().map(|&(guess, _)| guess)
+    }
+
+    /// 지금까지 추측한 숫자와 비교 결과, 추측한 순서대로
+    pub fn guesses(&self) -> &[(u32, Ordering)] {
+        &self.guesses
+    }
+
+    /// 지금까지의 비교 결과로 보아 비밀 숫자가 있을 수 있는 구간 (양 끝 포함)
+    pub fn candidates(&self) -> (u32, u32) {
+        (self.low, self.high)
+    }
+
+    pub fn hint_used(&self) -> bool {
+        self.hint_used
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.last == Some(Ordering::Equal)
+    }
+
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && self.remaining() == Some(0)
+    }
+
+    /// 추측 하나를 처리하고 비밀 숫자와 비교한 결과를 돌려준다.
+    /// 추측한 숫자가 비밀 숫자보다 작으면 Less, 크면 Greater 이다.
+    pub fn guess(&mut self, guess: u32) -> Ordering {
+        let ordering = guess.cmp(&self.secret);
+        match ordering {
+            // guess < secret 이므로 guess + 1은 넘치지 않는다. 아래 guess - 1도 마찬가지.
+            Ordering::Less => self.low = self.low.max(guess + 1),
+            Ordering::Greater => self.high = self.high.min(guess - 1),
+            Ordering::Equal => {
+                self.low = guess;
+                self.high = guess;
+            }
+        }
+        self.attempts += 1;
+        self.last = Some(ordering);
+        self.last_guess = Some(guess);
+        self.guesses.push((guess, ordering));
+        ordering
+    }
+
+    /// 한 번만 쓸 수 있는 짝수/3의 배수 힌트. 쓰면 시도 횟수가 하나 늘어난다.
+    /// 이미 썼거나 게임이 끝났으면 None 이다.
+    pub fn use_hint(&mut self) -> Option<NumberHint> {
+        if self.hint_used || self.is_won() || self.is_lost() {
+            return None;
+        }
+        self.hint_used = true;
+        self.attempts += 1;
+        Some(NumberHint::of(self.secret))
+    }
+
+    /// 입력 핸들에서 한 줄씩 읽어가며 숫자를 맞출 때까지 게임을 진행하고 출력 핸들에 결과를 쓴다.
+    /// stdin/stdout 대신 아무 BufRead/Write나 넘길 수 있으므로 테스트에서는 바이트 슬라이스와 Vec<u8>을 넘기면 된다.
+    pub fn play<R: BufRead, W: Write>(&mut self, mut input: R, mut output: W) -> io::Result<Outcome> {
+        self.start(&mut output)?;
+        loop {
+            if let Some(outcome) = self.turn(&mut input, &mut output)? {
+                return Ok(outcome);
+            }
+        }
+    }
+
+    /// 이번 추측을 기다리기 시작한다. 이미 기다리고 있으면 아무것도 하지 않는다.
+    /// turn이 알아서 부르므로 respond로 직접 진행할 때(tui.rs)만 부르면 된다.
+    pub fn begin_turn(&mut self) {
+        let now = self.clock.now();
+        let started = *self.started.get_or_insert(now);
+        let turn_started = *self.turn_started.get_or_insert(now);
+        let turn = self.config.guess_time.map(|limit| turn_started + limit);
+        let total = self.config.time_limit.map(|limit| started + limit);
+        self.deadline.set(turn.into_iter().chain(total).min());
+    }
+
+    /// 이번 추측에 남은 시간. 추측 시간 제한이 없으면 None 이다.
+    pub fn guess_time_left(&self) -> Option<Duration> {
+        let limit = self.config.guess_time?;
+        let waited = self.turn_started.map_or(Duration::from_secs(0), |started| self.clock.now() - started);
+        Some(limit.checked_sub(waited).unwrap_or_default())
+    }
+
+    /// 게임 전체에 남은 시간. 전체 시간 제한이 없으면 None 이다.
+    pub fn time_left(&self) -> Option<Duration> {
+        let limit = self.config.time_limit?;
+        Some(limit.checked_sub(self.elapsed()).unwrap_or_default())
+    }
+
+    /// 입력 한 줄(추측 또는 힌트 요청)을 처리하고 결과를 출력 핸들에 쓴다.
+    /// 잘못된 입력이면 Retry, 시도 하나를 썼으면 Next, 게임이 끝났으면 Over(결과) 이다.
+    /// 줄을 직접 읽어서 넘기는 tui.rs, multiplayer.rs, replay.rs가 쓴다. 읽기까지 맡기려면 turn을 쓴다.
+    pub fn respond<W: Write>(&mut self, line: &str, mut output: W) -> io::Result<Step> {
+        self.begin_turn();
+        let mut events = Vec::new();
+        let result = self.read_move(line.trim(), &mut events);
+        self.log_all(events)?;
+        match result {
+            Ok(next) => self.apply(next, output),
+            Err(reason) => {
+                writeln!(output, "{}", reason)?;
+                Ok(Step::Retry)
+            }
+        }
+    }
+
+    /// 앞뒤 공백을 잘라낸 입력 한 줄을 이번 차례에 할 일로 바꾼다. 게임 상태는 바꾸지 않는다.
+    /// 추측으로 받아들일 수 없는 입력이면 이유를 돌려준다. 남길 이벤트는 events에 넣는다.
+    fn read_move(&self, line: &str, events: &mut Vec<Event>) -> Result<Move, String> {
+        if self.timeout().is_some() {
+            return Ok(Move::Late);
+        }
+        if self.config.hints && is_hint_request(line) {
+            return if self.hint_used || self.is_won() || self.is_lost() {
+                Err("힌트는 한 번만 쓸 수 있습니다.".to_string())
+            } else {
+                Ok(Move::Hint)
+            };
+        }
+        let result = parse_guess(line, self.config.min, self.config.max);
+        events.push(Event::Guess { input: line.to_string(), result: result.clone() });
+        result.map(Move::Guess).map_err(|error| error.to_string())
+    }
+
+    fn log_all(&mut self, events: Vec<Event>) -> io::Result<()> {
+        for event in events {
+            self.log(event)?;
+        }
+        Ok(())
+    }
+
+    /// read_move가 받아들인 할 일을 하고 결과를 출력 핸들에 쓴다.
+    fn apply<W: Write>(&mut self, next: Move, mut output: W) -> io::Result<Step> {
+        match next {
+            Move::Late => return self.time_up(output).map(Step::Over),
+            Move::Hint => match self.use_hint() {
+                Some(hint) => {
+                    writeln!(output, "힌트: {} (기회를 하나 썼습니다.)", hint)?;
+                    self.log(Event::Hint { attempt: self.attempts })?;
+                }
+                None => {
+                    writeln!(output, "힌트는 한 번만 쓸 수 있습니다.")?;
+                    return Ok(Step::Retry);
+                }
+            },
+            Move::Guess(guess) => {
+                let previous = self.last_guess;
+                writeln!(output, "님이 입력한 숫자는 {} 입니다.", guess)?;
+                let ordering = self.guess(guess);
+                self.log(Event::Compare { attempt: self.attempts, guess, ordering })?;
+                match ordering {
+                    Ordering::Less      => writeln!(output, "너무 작음")?,
+                    Ordering::Greater   => writeln!(output, "너무 큼")?,
+                    Ordering::Equal     => {
+                        writeln!(output, "님이 맞춤")?;
+                        let outcome = self.finish(Outcome::Won(self.attempts))?;
+                        if self.is_timed() {
+                            writeln!(output, "걸린 시간: {:.1}초", self.elapsed().as_secs_f64())?;
+                        }
+                        return Ok(Step::Over(outcome));
+                    }
+                }
+                if self.config.speedrun {
+                    writeln!(output, "[스피드런] {:.1}초", self.elapsed().as_secs_f64())?;
+                }
+                if self.config.hints {
+                    let temperature = Temperature::of(guess, self.secret, self.config.min, self.config.max);
+                    match previous {
+                        Some(previous) => writeln!(output, "{} ({})", temperature, Trend::of(previous, guess, self.secret))?,
+                        None => writeln!(output, "{}", temperature)?,
+                    }
+                }
+            }
+        }
+        self.turn_started = None;
+        self.deadline.set(None);
+        if self.config.debug {
+            writeln!(output, "[디버그] 남은 후보 구간: {} ~ {} (시도 {}번)", self.low, self.high, self.attempts)?;
+        }
+        if write_remaining(&mut output, self.remaining(), self.secret)? {
+            return self.finish(Outcome::Lost).map(Step::Over);
+        }
+        Ok(Step::Next)
+    }
+
+    /// 숫자를 맞추기 전에 입력이 끝났을 때 게임을 끝낸다.
+    pub fn end_of_input<W: Write>(&mut self, mut output: W) -> io::Result<Outcome> {
+        writeln!(output, "{}", END_OF_INPUT)?;
+        self.finish(Outcome::Quit)
+    }
+
+    /// 게임 시작을 기록하고 안내(intro)를 출력한다.
+    pub fn start<W: Write>(&mut self, output: W) -> io::Result<()> {
+        let (min, max, max_attempts, seed) = (self.config.min, self.config.max, self.config.max_attempts, self.config.seed);
+        self.log(Event::Start { min, max, max_attempts, seed })?;
+        self.intro(output)
+    }
+
+    /// 게임을 시작할 때 범위, 기회, 힌트 사용법을 알려준다.
+    pub fn intro<W: Write>(&self, mut output: W) -> io::Result<()> {
+        writeln!(output, "{} 부터 {} 사이의 숫자를 맞춰보세요.", self.config.min, self.config.max)?;
+        write_max_attempts(&mut output, self.config.max_attempts)?;
+        if self.config.hints {
+            writeln!(output, "'힌트'를 입력하면 기회 하나를 써서 비밀 숫자가 짝수인지, 3의 배수인지 알 수 있습니다. (한 번만)")?;
+        }
+        if self.config.debug {
+            writeln!(output, "[디버그] 비밀 숫자는 {} 입니다.", self.secret)?;
+        }
+        Ok(())
+    }
+
+    /// 추측(또는 힌트) 하나를 처리할 때까지 입력을 읽는다. 잘못된 입력은 이유를 알려주고 다시 읽는다.
+    /// 게임이 끝났으면 Some(결과), 계속 진행해야 하면 None을 돌려준다.
+    ///
+    /// 제한 시간은 입력 한 줄을 받은 순간에 확인한다. 시간을 넘겨서 들어온 추측은 처리하지 않고 게임을 끝낸다.
+    /// 입력을 clock::TimedInput으로 감싸면 줄이 들어오기 전이라도 제한 시간이 지나는 순간 끝난다. (읽기가 TimedOut 에러를 냄)
+    pub fn turn<R: BufRead, W: Write>(&mut self, mut input: R, mut output: W) -> io::Result<Option<Outcome>> {
+        self.begin_turn();
+        loop {
+            // 묻기 → 읽기 → 파싱 → 다시 묻기는 Prompt가 한다. 묻는 문장 앞의 남은 시간은 물을 때마다 새로 계산한다.
+            let mut events = Vec::new();
+            let result = {
+                let game = &*self;
+                Prompt::new(&mut input, &mut output).ask_with(Countdown(game), |line| game.read_move(line, &mut events))
+            };
+            self.log_all(events)?;
+            let step = match result {
+                Ok(next) => self.apply(next, &mut output)?,
+                Err(PromptError::Eof) => return self.end_of_input(&mut output).map(Some),
+                Err(PromptError::Io(ref error)) if error.kind() == io::ErrorKind::TimedOut => {
+                    return self.time_up(&mut output).map(Some)
+                }
+                Err(PromptError::Io(error)) => return Err(error),
+            };
+            match step {
+                Step::Retry => continue,
+                Step::Next => return Ok(None),
+                Step::Over(outcome) => return Ok(Some(outcome)),
+            }
+        }
+        /*
+        cmp 메소드는 비교가 가능한 모든것들에 대해 호출할 수 있다고 한다. cmp 메소드는 비교하고 싶은 것들의 참조자를 받는다.
+        cmp는 Ordering 타입의 열거형을 리턴하기 때문에 use std::cmp::Ordering;을 사용하였다.
+        match 표현문을 이용해 Ordering의 값에 따라 무엇을 할지 정할수 있다.
+
+        match 표현식은 arm으로 이루어져 있다고 한다.
+        하나의 arm은 하나의 패펀과 match 표현식에서 주어진 값이 패턴과 맞을때 실행할 코드로 이루어져 있다.
+        match에 대한 것은 추후에 자세히 다룬다.
+
+        러스트는 정적 타입 시스템을 가지고 있지만
+            - 8줄, 10줄은 각 타입이 명확하기 때문에 타입을 적지 않는다.
+        타입을 추론이 필요할 경우 동적으로 추론한다.
+        하지만 위 stdin을 통해 입력한 데이터는 문자열 형이고 랜덤하게 생성한 데이터는 숫자이다. 그래서 별도로 형변환을 해야 한다.
+
+        문자열 타입 guess는 trim, parse 메소드를 가지고 있다.
+        trim 메소드는 처음과 끝의 빈칸 혹은 개행을 제거한다.
+        parse 메소드는 문자열을 숫자로 파싱한다. 하지만 이 메소드는 다양한 형태의 숫자로 반환할 수 있으므로 타입을 명시할 필요가 있다.
+        이 예제에선 변수를 선언할 때 타입을 정하는 것으로 하였다. 변수명 뒤 콜론은 변수의 타입을 명시한 것이다. (unsigned 32bit)
+
+        parse 메소드는 예외가 발생할 가능성이 높다. 문자열 내 숫자가 있지 않을 가능성도 있기 때문이다.
+        parse 메소드의 리턴값도 실패할 경우를 위해 io:Result 타입을 리턴해준다.
+        그래서 expect 메소드를 실행해도 되고 match 표현식으로 처리해도 된다. (보통 match 표현식으로 처리한다.)
+        저 이상한 모양새가 동작할 수 있는 이유는 parse 메소드가 오류가 발생하지 않았다면 Result 타입은 Ok를 반환하고 이어서 num을 guess에 넣는다.
+        Err과 매칭된다면 (인수의 언더바는 모든값과 매칭될 수 있다. 따라서 모든 에러를 핸들링할 수 있다.) continue 키워드가 동작되 루프의 처음으로 돌아간다.
+        지금은 parse 대신 input::parse_guess를 써서 빈 입력, 숫자가 아닌 입력, 음수, 너무 큰 수, 범위 밖의 수를 구분하고
+        Err를 돌려주면 Prompt::ask_with가 무엇이 잘못됐는지 출력하고 다시 묻는다. 이런 입력은 self.guess를 부르지 않으므로 시도 횟수에 들어가지 않는다.
+
+        loop 키워드는 while (1)과 동일하다. 내부에서 break 키워드로 빠져나오기 전까지 무한반복을 수행한다. continue 키워드를 쓸수도 있다.
+        read_line이 0을 돌려주면 더 읽을 입력이 없다는 뜻(EOF)이다. 지금은 Prompt가 읽고 이때 PromptError::Eof를 돌려준다.
+        */
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{ManualClock, TimedInput};
+    use std::collections::VecDeque;
+    use std::io::{BufReader, Read};
+    use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+    use std::sync::mpsc::{self, Receiver};
+    use std::sync::Arc;
+    use std::thread;
+
+    /// 한 줄을 내줄 때마다 정해둔 만큼 시계를 움직이는 입력. 플레이어가 생각하는 시간을 흉내낸다.
+    struct SlowInput {
+        clock: ManualClock,
+        lines: VecDeque<(u64, &'static str)>,
+        buffer: Vec<u8>,
+    }
+
+    impl SlowInput {
+        /// (입력하기까지 걸린 밀리초, 입력한 줄)
+        fn new(clock: &ManualClock, lines: &[(u64, &'static str)]) -> SlowInput {
+            SlowInput { clock: clock.clone(), lines: lines.iter().cloned().collect(), buffer: Vec::new() }
+        }
+    }
+
+    impl Read for SlowInput {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.fill_buf()?.read(buf)?;
+            self.consume(n);
+            Ok(n)
+        }
+    }
+
+    impl BufRead for SlowInput {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            if self.buffer.is_empty() {
+                if let Some((millis, line)) = self.lines.pop_front() {
+                    self.clock.advance(Duration::from_millis(millis));
+                    self.buffer = format!("{}\n", line).into_bytes();
+                }
+            }
+            Ok(&self.buffer)
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.buffer.drain(..amt);
+        }
+    }
+
+    #[test]
+    fn guess_updates_state() {
+        let mut game = Game::new(42);
+        assert_eq!(game.last(), None);
+        assert_eq!(game.guess(10), Ordering::Less);
+        assert_eq!(game.guess(90), Ordering::Greater);
+        assert!(!game.is_won());
+        assert_eq!(game.guess(42), Ordering::Equal);
+        assert!(game.is_won());
+        assert_eq!(game.attempts(), 3);
+    }
+
+    #[test]
+    fn candidates_narrow_with_each_guess() {
+        let mut game = Game::new(42);
+        assert_eq!(game.candidates(), (1, 100));
+        game.guess(30);
+        game.guess(50);
+        assert_eq!(game.candidates(), (31, 49));
+        // 이미 범위 밖인 추측은 구간을 넓히지 않는다.
+        game.guess(10);
+        assert_eq!(game.candidates(), (31, 49));
+    }
+
+    #[test]
+    fn secret_is_hidden_unless_debug() {
+        let mut output = Vec::new();
+        Game::new(42).play(&b"50\n"[..], &mut output).unwrap();
+        assert!(!String::from_utf8(output).unwrap().contains("42"));
+
+        let config = Config { debug: true, ..Config::default() };
+        let mut output = Vec::new();
+        Game::with_config(42, config).play(&b"50\n"[..], &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("[디버그] 비밀 숫자는 42 입니다."));
+        assert!(output.contains("[디버그] 남은 후보 구간: 1 ~ 49 (시도 1번)"));
+    }
+
+    #[test]
+    fn secret_from_rng_is_in_range() {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let config = Config { min: 10, max: 20, ..Config::default() };
+        for _ in 0..1000 {
+            let game = Game::with_rng(&mut rng, config.clone());
+            assert!(game.secret() >= 10 && game.secret() <= 20);
+        }
+    }
+
+    #[test]
+    fn test_support_knows_the_seeded_secret() {
+        // 통합 테스트는 test_support::seeded_secret_and_miss로 바이너리의 비밀 숫자를 미리 알아낸다.
+        for seed in 0..50 {
+            let (secret, miss) = test_support::seeded_secret_and_miss(seed);
+            assert_eq!(secret, Game::with_rng(&mut seeded_rng(seed), Config::default()).secret());
+            assert_ne!(miss, secret);
+        }
+    }
+
+    #[test]
+    fn same_seed_same_secret() {
+        let config = Config { min: 1, max: 1_000_000, ..Config::default() };
+        let a = Game::with_rng(&mut seeded_rng(42), config.clone());
+        let b = Game::with_rng(&mut seeded_rng(42), config.clone());
+        let c = Game::with_rng(&mut seeded_rng(43), config);
+        assert_eq!(a.secret(), b.secret());
+        assert_ne!(a.secret(), c.secret());
+    }
+
+    #[test]
+    fn play_ends_when_attempts_run_out() {
+        let config = Config { max_attempts: Some(2), ..Config::default() };
+        let mut game = Game::with_config(42, config);
+        let mut output = Vec::new();
+        let outcome = game.play(&b"10\n20\n42\n"[..], &mut output).unwrap();
+        assert_eq!(outcome, Outcome::Lost);
+        assert!(game.is_lost());
+        assert!(String::from_utf8(output).unwrap().ends_with("정답은 42 였습니다.\n"));
+    }
+
+    #[test]
+    fn rejected_input_is_not_an_attempt() {
+        let mut game = Game::new(42);
+        let mut output = Vec::new();
+        let outcome = game.play(&b"\nabc\n-3\n0\n101\n99999999999\n42\n"[..], &mut output).unwrap();
+        assert_eq!(outcome, Outcome::Won(1));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("아무것도 입력하지 않았습니다."));
+        assert!(output.contains("'abc'은(는) 숫자가 아닙니다."));
+        assert!(output.contains("-3은(는) 음수입니다."));
+        assert!(output.contains("0은(는) 범위 밖입니다."));
+        assert!(output.contains("101은(는) 범위 밖입니다."));
+        assert!(output.contains("99999999999은(는) 너무 큰 숫자입니다."));
+    }
+
+    #[test]
+    fn hints_mode() {
+        let config = Config { hints: true, max_attempts: Some(4), ..Config::default() };
+        let mut game = Game::with_config(42, config);
+        let mut output = Vec::new();
+        let outcome = game.play("90\n40\n힌트\nhint\n43\n".as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("너무 큼\n차가움\n"));
+        assert!(output.contains("너무 작음\n아주 뜨거움 (이전 추측보다 가까워졌습니다)\n"));
+        assert!(output.contains("힌트: 비밀 숫자는 짝수이고 3의 배수입니다. (기회를 하나 썼습니다.)"));
+        assert!(output.contains("힌트는 한 번만 쓸 수 있습니다."));
+        // 힌트도 기회를 하나 쓰므로 43을 부른 네 번째 시도에서 진다.
+        assert_eq!(outcome, Outcome::Lost);
+        assert_eq!(game.attempts(), 4);
+    }
+
+    #[test]
+    fn hint_request_is_a_plain_invalid_input_without_hints() {
+        let mut game = Game::new(42);
+        let mut output = Vec::new();
+        game.play(&b"hint\n"[..], &mut output).unwrap();
+        assert!(String::from_utf8(output).unwrap().contains("'hint'은(는) 숫자가 아닙니다."));
+        assert_eq!(game.attempts(), 0);
+    }
+
+    #[test]
+    fn play_stops_on_eof() {
+        let mut game = Game::new(42);
+        let mut output = Vec::new();
+        let outcome = game.play(&b"10\n"[..], &mut output).unwrap();
+        assert_eq!(outcome, Outcome::Quit);
+        assert_eq!(game.attempts(), 1);
+    }
+
+    #[test]
+    fn non_utf8_line_is_rejected_and_retried() {
+        let mut game = Game::new(42);
+        let mut output = Vec::new();
+        let outcome = game.play(&b"50\n\xff\xfe\n42\n"[..], &mut output).unwrap();
+        assert_eq!(outcome, Outcome::Won(2));
+        assert!(String::from_utf8(output).unwrap().contains("읽을 수 없는 글자가 있습니다. 숫자를 입력해주세요.\n숫자를 입력하세여\n"));
+    }
+
+    #[test]
+    fn guess_timer_ends_the_game() {
+        let clock = ManualClock::new();
+        let config = Config { guess_time: Some(Duration::from_secs(10)), ..Config::default() };
+        let mut game = Game::with_config(42, config).with_clock(clock.clone());
+        let mut output = Vec::new();
+        // 잘못된 입력을 해도 이번 추측의 시간은 계속 흐른다.
+        let input = SlowInput::new(&clock, &[(9_000, "50"), (6_000, "abc"), (5_000, "42")]);
+        let outcome = game.play(input, &mut output).unwrap();
+        assert_eq!(outcome, Outcome::TimedOut);
+        assert_eq!(game.attempts(), 1);
+        assert_eq!(game.elapsed(), Duration::from_secs(20));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("(이번 추측 남은 시간 10.0초) 숫자를 입력하세여\n"));
+        assert!(output.contains("(이번 추측 남은 시간 4.0초) 숫자를 입력하세여\n"));
+        assert!(output.ends_with("시간 초과! 한 번에 쓸 수 있는 시간 10초가 지났습니다. 정답은 42 였습니다.\n"));
+    }
+
+    /// 채널로 받은 바이트만 내주는 입력. 보내는 쪽이 아무것도 보내지 않으면 계속 기다린다.
+    struct Silent(Receiver<Vec<u8>>);
+
+    impl Read for Silent {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.recv() {
+                Ok(bytes) => (&bytes[..]).read(buf),
+                Err(_) => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn guess_timer_ends_the_game_while_waiting_for_input() {
+        let clock = ManualClock::new();
+        let config = Config { guess_time: Some(Duration::from_secs(5)), ..Config::default() };
+        let mut game = Game::with_config(42, config).with_clock(clock.clone());
+        let (_sender, receiver) = mpsc::channel();
+        let input = TimedInput::new(BufReader::new(Silent(receiver)), clock.clone(), game.deadline());
+        // 입력이 하나도 들어오지 않는 동안 다른 스레드에서 시간이 흐른다.
+        let done = Arc::new(AtomicBool::new(false));
+        let ticker = {
+            let (clock, done) = (clock.clone(), done.clone());
+            thread::spawn(move || {
+                while !done.load(AtomicOrdering::SeqCst) {
+                    clock.advance(Duration::from_secs(1));
+                    thread::sleep(Duration::from_millis(10));
+                }
+            })
+        };
+        let mut output = Vec::new();
+        let outcome = game.play(input, &mut output).unwrap();
+        done.store(true, AtomicOrdering::SeqCst);
+        ticker.join().unwrap();
+        assert_eq!(outcome, Outcome::TimedOut);
+        assert_eq!(game.attempts(), 0);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.ends_with("시간 초과! 한 번에 쓸 수 있는 시간 5초가 지났습니다. 정답은 42 였습니다.\n"));
+    }
+
+    #[test]
+    fn total_timer_ends_the_game() {
+        let clock = ManualClock::new();
+        let config = Config { time_limit: Some(Duration::from_secs(30)), ..Config::default() };
+        let mut game = Game::with_config(42, config).with_clock(clock.clone());
+        let mut output = Vec::new();
+        let input = SlowInput::new(&clock, &[(20_000, "50"), (10_001, "42")]);
+        assert_eq!(game.play(input, &mut output).unwrap(), Outcome::TimedOut);
+        assert!(String::from_utf8(output).unwrap().contains("전체 제한 시간 30초가 지났습니다."));
+    }
+
+    #[test]
+    fn speedrun_reports_elapsed_time() {
+        let clock = ManualClock::new();
+        let config = Config { speedrun: true, ..Config::default() };
+        let mut game = Game::with_config(42, config).with_clock(clock.clone());
+        let mut output = Vec::new();
+        let input = SlowInput::new(&clock, &[(1_500, "50"), (2_000, "42")]);
+        assert_eq!(game.play(input, &mut output).unwrap(), Outcome::Won(2));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("너무 큼\n[스피드런] 1.5초\n"));
+        assert!(output.ends_with("님이 맞춤\n걸린 시간: 3.5초\n"));
+        // 끝난 뒤에는 시간이 흘러도 걸린 시간이 바뀌지 않는다.
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(game.elapsed(), Duration::from_millis(3_500));
+    }
+}
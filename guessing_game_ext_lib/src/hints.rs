@@ -0,0 +1,134 @@
+use std::fmt;
+
+/// 추측이 비밀 숫자에 얼마나 가까운지. 범위 크기에 대한 거리의 비율로 정한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Temperature {
+    /// 범위의 2% 이내
+    Burning,
+    /// 범위의 5% 이내
+    Hot,
+    /// 범위의 15% 이내
+    Warm,
+    Cold,
+}
+
+impl Temperature {
+    pub fn of(guess: u32, secret: u32, min: u32, max: u32) -> Temperature {
+        let distance = u64::from(guess.abs_diff(secret));
+        let size = u64::from(max - min) + 1;
+        // 범위가 작을 때에도 바로 옆 숫자는 항상 "아주 뜨거움"이 되도록 최소 1로 둔다.
+        let within = |percent: u64| distance <= (size * percent / 100).max(1);
+        if within(2) {
+            Temperature::Burning
+        } else if within(5) {
+            Temperature::Hot
+        } else if within(15) {
+            Temperature::Warm
+        } else {
+            Temperature::Cold
+        }
+    }
+}
+
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Temperature::Burning => "아주 뜨거움",
+            Temperature::Hot => "뜨거움",
+            Temperature::Warm => "따뜻함",
+            Temperature::Cold => "차가움",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// 이번 추측이 이전 추측보다 비밀 숫자에 가까워졌는지
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Closer,
+    Farther,
+    Same,
+}
+
+impl Trend {
+    pub fn of(previous: u32, guess: u32, secret: u32) -> Trend {
+        match guess.abs_diff(secret).cmp(&previous.abs_diff(secret)) {
+            std::cmp::Ordering::Less => Trend::Closer,
+            std::cmp::Ordering::Greater => Trend::Farther,
+            std::cmp::Ordering::Equal => Trend::Same,
+        }
+    }
+}
+
+impl fmt::Display for Trend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Trend::Closer => "이전 추측보다 가까워졌습니다",
+            Trend::Farther => "이전 추측보다 멀어졌습니다",
+            Trend::Same => "이전 추측과 거리가 같습니다",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// 힌트를 달라는 입력인지
+pub fn is_hint_request(line: &str) -> bool {
+    matches!(line.trim().to_lowercase().as_str(), "힌트" | "hint" | "?")
+}
+
+/// 한 번만 쓸 수 있는 힌트. 비밀 숫자가 짝수인지, 3의 배수인지 알려준다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberHint {
+    pub even: bool,
+    pub multiple_of_three: bool,
+}
+
+impl NumberHint {
+    pub fn of(secret: u32) -> NumberHint {
+        NumberHint {
+            even: secret.is_multiple_of(2),
+            multiple_of_three: secret.is_multiple_of(3),
+        }
+    }
+}
+
+impl fmt::Display for NumberHint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "비밀 숫자는 {}이고 3의 배수{}.",
+            if self.even { "짝수" } else { "홀수" },
+            if self.multiple_of_three { "입니다" } else { "가 아닙니다" }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn temperature_grades() {
+        assert_eq!(Temperature::of(41, 42, 1, 100), Temperature::Burning);
+        assert_eq!(Temperature::of(46, 42, 1, 100), Temperature::Hot);
+        assert_eq!(Temperature::of(30, 42, 1, 100), Temperature::Warm);
+        assert_eq!(Temperature::of(90, 42, 1, 100), Temperature::Cold);
+        // 범위가 작아도 바로 옆 숫자는 아주 뜨거움
+        assert_eq!(Temperature::of(3, 4, 1, 10), Temperature::Burning);
+    }
+
+    #[test]
+    fn trend() {
+        assert_eq!(Trend::of(10, 40, 42), Trend::Closer);
+        assert_eq!(Trend::of(40, 10, 42), Trend::Farther);
+        assert_eq!(Trend::of(40, 44, 42), Trend::Same);
+    }
+
+    #[test]
+    fn number_hint() {
+        assert_eq!(NumberHint::of(42).to_string(), "비밀 숫자는 짝수이고 3의 배수입니다.");
+        assert_eq!(NumberHint::of(7).to_string(), "비밀 숫자는 홀수이고 3의 배수가 아닙니다.");
+        assert!(is_hint_request(" 힌트\n"));
+        assert!(!is_hint_request("42"));
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::game::{Game, Outcome};
+use crate::scores::{format_timestamp, unix_now};
+
+/// 기록 파일을 지정하지 않았을 때 사용하는 경로 (현재 디렉토리 기준)
+pub const DEFAULT_HISTORY_FILE: &str = "history.txt";
+
+/// 히스토그램 막대의 최대 길이
+const BAR_WIDTH: usize = 40;
+
+/// 끝난 게임(이겼거나 기회를 다 쓴 게임) 한 판의 기록.
+/// 점수 파일은 이긴 게임만 남기지만 이 파일은 진 게임도 남겨서 승률과 변화를 볼 수 있게 한다.
+///
+/// `시각(유닉스 초)\t이름\t최솟값\t최댓값\t결과(won/lost)\t시도 횟수\t첫 추측(없으면 -)\t걸린 시간(ms)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub timestamp: u64,
+    pub name: String,
+    pub min: u32,
+    pub max: u32,
+    pub won: bool,
+    pub attempts: u32,
+    pub first_guess: Option<u32>,
+    pub duration: Duration,
+}
+
+impl Entry {
+    /// 방금 끝난 게임의 기록을 만든다. 끝나지 않은 게임(Quit)은 None 이고 시간 초과는 진 게임으로 센다.
+    pub fn of(game: &Game, config: &Config, outcome: Outcome, duration: Duration) -> Option<Entry> {
+        let won = match outcome {
+            Outcome::Won(_) => true,
+            Outcome::Lost | Outcome::TimedOut => false,
+            Outcome::Quit => return None,
+        };
+        Some(Entry {
+            timestamp: unix_now(),
+            name: config.name.replace(['\t', '\n', '\r'], " "),
+            min: config.min,
+            max: config.max,
+            won,
+            attempts: game.attempts(),
+            first_guess: game.first_guess(),
+            duration,
+        })
+    }
+
+    pub fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.name,
+            self.min,
+            self.max,
+            if self.won { "won" } else { "lost" },
+            self.attempts,
+            self.first_guess.map_or_else(|| "-".to_string(), |guess| guess.to_string()),
+            self.duration.as_millis()
+        )
+    }
+
+    /// 기록 파일의 한 줄을 읽는다. 형식이 맞지 않으면 None을 돌려준다.
+    pub fn parse(line: &str) -> Option<Entry> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() != 8 {
+            return None;
+        }
+        Some(Entry {
+            timestamp: fields[0].parse().ok()?,
+            name: fields[1].to_string(),
+            min: fields[2].parse().ok()?,
+            max: fields[3].parse().ok()?,
+            won: match fields[4] {
+                "won" => true,
+                "lost" => false,
+                _ => return None,
+            },
+            attempts: fields[5].parse().ok()?,
+            first_guess: match fields[6] {
+                "-" => None,
+                guess => Some(guess.parse().ok()?),
+            },
+            duration: Duration::from_millis(fields[7].parse().ok()?),
+        })
+    }
+}
+
+/// 기록 파일의 끝에 한 판을 덧붙인다. 파일이 없으면 만든다.
+pub fn append<P: AsRef<Path>>(path: P, entry: &Entry) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", entry.to_line())
+}
+
+/// 기록 파일을 읽는다. 파일이 없으면 빈 기록이고, 형식이 깨진 줄은 건너뛰고 그 개수를 함께 돌려준다.
+pub fn load<P: AsRef<Path>>(path: P) -> io::Result<(Vec<Entry>, usize)> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(ref error) if error.kind() == ErrorKind::NotFound => return Ok((Vec::new(), 0)),
+        Err(error) => return Err(error),
+    };
+    let mut entries = Vec::new();
+    let mut skipped = 0;
+    for line in String::from_utf8_lossy(&bytes).lines().filter(|line| !line.trim().is_empty()) {
+        match Entry::parse(line) {
+            Some(entry) => entries.push(entry),
+            None => skipped += 1,
+        }
+    }
+    Ok((entries, skipped))
+}
+
+/// 기록들로부터 계산한 통계. 시도 횟수에 관한 값은 이긴 게임만으로 계산한다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub played: usize,
+    pub won: usize,
+    pub average: Option<f64>,
+    pub median: Option<f64>,
+    /// (시도 횟수, 그 횟수로 이긴 게임 수), 시도 횟수 순
+    pub distribution: Vec<(u32, usize)>,
+    /// (가장 많이 쓴 첫 추측, 쓴 횟수). 횟수가 같으면 작은 숫자
+    pub first_guess: Option<(u32, usize)>,
+    /// 이긴 게임을 시간 순으로 반으로 나눴을 때 (앞쪽 평균, 뒤쪽 평균). 두 판 이상 이겨야 있다.
+    pub trend: Option<(f64, f64)>,
+}
+
+impl Stats {
+    pub fn of(entries: &[Entry]) -> Stats {
+        let mut wins: Vec<&Entry> = entries.iter().filter(|entry| entry.won).collect();
+        wins.sort_by_key(|entry| entry.timestamp);
+        let attempts: Vec<u32> = wins.iter().map(|entry| entry.attempts).collect();
+
+        let mut sorted = attempts.clone();
+        sorted.sort_unstable();
+        let median = match sorted.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(f64::from(sorted[n / 2])),
+            n => Some(f64::from(sorted[n / 2 - 1] + sorted[n / 2]) / 2.0),
+        };
+
+        let mut distribution: Vec<(u32, usize)> = Vec::new();
+        for &n in &sorted {
+            match distribution.last_mut() {
+                Some(last) if last.0 == n => last.1 += 1,
+                _ => distribution.push((n, 1)),
+            }
+        }
+
+        let mut first_guesses: HashMap<u32, usize> = HashMap::new();
+        for guess in entries.iter().filter_map(|entry| entry.first_guess) {
+            *first_guesses.entry(guess).or_insert(0) += 1;
+        }
+        let first_guess = first_guesses
+            .into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)));
+
+        let trend = if attempts.len() >= 2 {
+            let (earlier, later) = attempts.split_at(attempts.len() / 2);
+            Some((average(earlier).unwrap_or(0.0), average(later).unwrap_or(0.0)))
+        } else {
+            None
+        };
+
+        Stats {
+            played: entries.len(),
+            won: wins.len(),
+            average: average(&attempts),
+            median,
+            distribution,
+            first_guess,
+            trend,
+        }
+    }
+
+    pub fn win_rate(&self) -> Option<f64> {
+        if self.played == 0 {
+            None
+        } else {
+            Some(self.won as f64 / self.played as f64 * 100.0)
+        }
+    }
+
+    /// 통계를 터미널용 보고서로 출력한다. 시도 횟수 분포는 '#' 막대 그래프로 그린다.
+    pub fn print<W: Write>(&self, mut output: W) -> io::Result<()> {
+        let win_rate = match self.win_rate() {
+            Some(win_rate) => win_rate,
+            None => return writeln!(output, "아직 끝난 게임이 없습니다."),
+        };
+        writeln!(output, "게임 수: {} (이김 {}, 짐 {})", self.played, self.won, self.played - self.won)?;
+        writeln!(output, "승률: {:.1}%", win_rate)?;
+        if let (Some(average), Some(median)) = (self.average, self.median) {
+            writeln!(output, "시도 횟수 (이긴 게임): 평균 {:.2}번, 중앙값 {:.1}번", average, median)?;
+        }
+        if let Some((guess, count)) = self.first_guess {
+            writeln!(output, "가장 많이 쓴 첫 추측: {} ({}번)", guess, count)?;
+        }
+        if let Some((earlier, later)) = self.trend {
+            writeln!(output, "평균 시도 횟수 변화: 앞쪽 절반 {:.2}번 -> 최근 절반 {:.2}번", earlier, later)?;
+        }
+        if !self.distribution.is_empty() {
+            writeln!(output, "시도 횟수 분포:")?;
+            let most = self.distribution.iter().map(|&(_, count)| count).max().unwrap_or(1);
+            for &(attempts, count) in &self.distribution {
+                // 한 판뿐인 칸도 보이도록 최소 1칸은 그린다.
+                let width = (count * BAR_WIDTH).div_ceil(most).max(1);
+                writeln!(output, "{:>3}번 | {} {}", attempts, "#".repeat(width), count)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn average(values: &[u32]) -> Option<f64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.iter().map(|&n| f64::from(n)).sum::<f64>() / values.len() as f64)
+    }
+}
+
+/// 기록 파일을 읽어 통계 보고서를 출력한다.
+pub fn print_report<P: AsRef<Path>, W: Write>(path: P, mut output: W) -> io::Result<()> {
+    let (entries, skipped) = load(path)?;
+    if skipped > 0 {
+        writeln!(output, "(기록 파일에서 읽을 수 없는 줄 {}개를 건너뛰었습니다.)", skipped)?;
+    }
+    if let (Some(first), Some(last)) = (entries.first(), entries.last()) {
+        writeln!(output, "기간: {} ~ {}", format_timestamp(first.timestamp), format_timestamp(last.timestamp))?;
+    }
+    Stats::of(&entries).print(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: u64, won: bool, attempts: u32, first_guess: Option<u32>) -> Entry {
+        Entry {
+            timestamp,
+            name: "철수".to_string(),
+            min: 1,
+            max: 100,
+            won,
+            attempts,
+            first_guess,
+            duration: Duration::from_millis(1234),
+        }
+    }
+
+    #[test]
+    fn line_round_trip() {
+        for entry in &[entry(10, true, 5, Some(50)), entry(11, false, 7, None)] {
+            assert_eq!(Entry::parse(&entry.to_line()).as_ref(), Some(entry));
+        }
+        assert_eq!(Entry::parse("10\t철수\t1\t100\tdraw\t5\t50\t0"), None);
+        assert_eq!(Entry::parse("10\t철수\t1\t100"), None);
+    }
+
+    #[test]
+    fn entry_of_finished_game() {
+        let mut game = Game::new(42);
+        game.guess(50);
+        game.guess(42);
+        let entry = Entry::of(&game, &Config::default(), Outcome::Won(2), Duration::from_secs(1)).unwrap();
+        assert_eq!((entry.won, entry.attempts, entry.first_guess), (true, 2, Some(50)));
+        assert_eq!(Entry::of(&game, &Config::default(), Outcome::Quit, Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    fn stats() {
+        let entries = vec![
+            entry(1, true, 9, Some(10)),
+            entry(2, false, 7, Some(10)),
+            entry(3, true, 7, Some(50)),
+            entry(4, true, 6, Some(50)),
+            entry(5, true, 6, Some(50)),
+        ];
+        let stats = Stats::of(&entries);
+        assert_eq!((stats.played, stats.won), (5, 4));
+        assert_eq!(stats.win_rate(), Some(80.0));
+        assert_eq!(stats.average, Some(7.0));
+        assert_eq!(stats.median, Some(6.5));
+        assert_eq!(stats.distribution, vec![(6, 2), (7, 1), (9, 1)]);
+        assert_eq!(stats.first_guess, Some((50, 3)));
+        assert_eq!(stats.trend, Some((8.0, 6.0)));
+
+        let mut output = Vec::new();
+        stats.print(&mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("승률: 80.0%\n"));
+        assert!(output.contains("평균 7.00번, 중앙값 6.5번"));
+        assert!(output.contains(&format!("  6번 | {} 2\n", "#".repeat(40))));
+        assert!(output.contains(&format!("  9번 | {} 1\n", "#".repeat(20))));
+    }
+
+    #[test]
+    fn empty_history() {
+        let stats = Stats::of(&[]);
+        assert_eq!(stats.win_rate(), None);
+        let mut output = Vec::new();
+        stats.print(&mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "아직 끝난 게임이 없습니다.\n");
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt;
+use std::num::IntErrorKind;
+
+/// 추측으로 받아들일 수 없는 입력. 이런 입력은 시도 횟수에 들어가지 않는다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuessError {
+    /// 빈 줄 (공백만 있는 경우 포함)
+    Empty,
+    /// UTF-8로 읽을 수 없는 바이트가 있다. 읽을 때 U+FFFD(�)로 바뀐 글자가 있는 줄을 이렇게 본다.
+    NotUtf8,
+    /// 숫자가 아닌 글자가 섞여 있다.
+    NotANumber(String),
+    /// 음수
+    Negative(String),
+    /// u32로 담을 수 없을 만큼 큰 수
+    Overflow(String),
+    /// 숫자이긴 하지만 게임 범위 밖이다.
+    OutOfRange { value: u32, min: u32, max: u32 },
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GuessError::Empty => write!(f, "아무것도 입력하지 않았습니다. 숫자를 입력해주세요."),
+            GuessError::NotUtf8 => write!(f, "읽을 수 없는 글자가 있습니다. 숫자를 입력해주세요."),
+            GuessError::NotANumber(text) => write!(f, "'{}'은(는) 숫자가 아닙니다.", text),
+            GuessError::Negative(text) => write!(f, "{}은(는) 음수입니다. 0 이상의 숫자를 입력해주세요.", text),
+            GuessError::Overflow(text) => write!(f, "{}은(는) 너무 큰 숫자입니다. (최대 {})", text, u32::MAX),
+            GuessError::OutOfRange { value, min, max } => {
+                write!(f, "{}은(는) 범위 밖입니다. {} 부터 {} 사이의 숫자를 입력해주세요.", value, min, max)
+            }
+        }
+    }
+}
+
+impl GuessError {
+    /// 거절한 이유를 나타내는 짧은 영어 이름. 구조화된 기록(events.rs)에 남긴다.
+    pub fn code(&self) -> &'static str {
+        match self {
+            GuessError::Empty => "empty",
+            GuessError::NotUtf8 => "not_utf8",
+            GuessError::NotANumber(_) => "not_a_number",
+            GuessError::Negative(_) => "negative",
+            GuessError::Overflow(_) => "overflow",
+            GuessError::OutOfRange { .. } => "out_of_range",
+        }
+    }
+}
+
+impl std::error::Error for GuessError {}
+
+/// 입력 한 줄을 min ~ max 범위의 추측으로 바꾼다. 앞뒤 공백과 개행은 무시한다.
+pub fn parse_guess(line: &str, min: u32, max: u32) -> Result<u32, GuessError> {
+    let text = line.trim();
+    if text.is_empty() {
+        return Err(GuessError::Empty);
+    }
+    if text.contains(char::REPLACEMENT_CHARACTER) {
+        return Err(GuessError::NotUtf8);
+    }
+    // u32::from_str는 "-0"도 InvalidDigit으로 거부하지만 -0은 음수가 아니라 0이므로 0으로 읽는다.
+    let unsigned = match text.strip_prefix('-') {
+        Some(zeros) if !zeros.is_empty() && zeros.chars().all(|c| c == '0') => zeros,
+        _ => text,
+    };
+    let value: u32 = match unsigned.parse() {
+        Ok(value) => value,
+        Err(error) => {
+            let digits = text.strip_prefix('-').unwrap_or(text);
+            let numeric = !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit());
+            return Err(match error.kind() {
+                IntErrorKind::PosOverflow => GuessError::Overflow(text.to_string()),
+                // "-0", "-00"은 위에서 0으로 읽었으므로 여기까지 오는 '-' 숫자는 모두 음수다.
+                _ if numeric && text.starts_with('-') => GuessError::Negative(text.to_string()),
+                _ => GuessError::NotANumber(text.to_string()),
+            });
+        }
+    };
+    if value < min || value > max {
+        return Err(GuessError::OutOfRange { value, min, max });
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_guess() {
+        assert_eq!(parse_guess(" 42 \n", 1, 100), Ok(42));
+        assert_eq!(parse_guess("+7", 1, 100), Ok(7));
+        assert_eq!(parse_guess("-0", 0, 100), Ok(0));
+        assert_eq!(parse_guess("-00", 0, 100), Ok(0));
+        assert_eq!(parse_guess("-0", 1, 100), Err(GuessError::OutOfRange { value: 0, min: 1, max: 100 }));
+        assert_eq!(parse_guess("-01", 1, 100), Err(GuessError::Negative("-01".to_string())));
+    }
+
+    #[test]
+    fn each_kind_of_invalid_input() {
+        assert_eq!(parse_guess("  \n", 1, 100), Err(GuessError::Empty));
+        assert_eq!(parse_guess("4\u{FFFD}", 1, 100), Err(GuessError::NotUtf8));
+        assert_eq!(parse_guess("열", 1, 100), Err(GuessError::NotANumber("열".to_string())));
+        assert_eq!(parse_guess("4.2", 1, 100), Err(GuessError::NotANumber("4.2".to_string())));
+        assert_eq!(parse_guess("-5", 1, 100), Err(GuessError::Negative("-5".to_string())));
+        assert_eq!(parse_guess("-", 1, 100), Err(GuessError::NotANumber("-".to_string())));
+        assert_eq!(parse_guess("4294967296", 1, 100), Err(GuessError::Overflow("4294967296".to_string())));
+        assert_eq!(parse_guess("0", 1, 100), Err(GuessError::OutOfRange { value: 0, min: 1, max: 100 }));
+        assert_eq!(parse_guess("5000", 1, 100), Err(GuessError::OutOfRange { value: 5000, min: 1, max: 100 }));
+    }
+}
@@ -0,0 +1,37 @@
+/*
+숫자 맞추기 게임의 로직을 바이너리(main.rs)에서 분리한 라이브러리 크레이트이다.
+main 안에 rand::thread_rng(), io::stdin(), println!이 모두 묶여 있으면 한 판의 게임을 테스트할 방법이 없다.
+그래서 게임 상태(비밀 숫자, 시도 횟수, 마지막 비교 결과)를 Game 구조체로 빼고
+난수 생성기와 입출력 핸들을 인수로 주입받도록 하였다.
+바이너리는 실제 thread_rng, stdin, stdout을 넘겨주기만 하는 얇은 껍데기가 된다.
+*/
+extern crate prompt;
+extern crate rand;
+
+pub mod bulls;
+pub mod clock;
+pub mod config;
+pub mod events;
+pub mod game;
+pub mod hints;
+pub mod history;
+pub mod input;
+pub mod multiplayer;
+pub mod replay;
+pub mod reverse;
+pub mod scores;
+pub mod script;
+pub mod server;
+pub mod solver;
+pub mod strategy;
+pub mod terminal;
+pub mod tournament;
+pub mod tui;
+pub mod words;
+
+pub use config::{Config, ConfigError, Difficulty, Mode};
+pub use game::{seeded_rng, Game, Outcome};
+pub use input::{parse_guess, GuessError};
+pub use replay::Replay;
+pub use scores::{Score, ScoreBoard};
+pub use strategy::Strategy;
@@ -1,57 +1,316 @@
 extern crate rand; // 의존성이 있는 외부 crate 추가. use rand로 표기해도 된다고 함
+extern crate guessing_game_ext_lib;
 
-use std::io;
-use std::cmp::Ordering;
-use rand::Rng; // 점수 생성기가 구현한 메소드들을 정의한 trait (trait가 무엇인지는 나중에)
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal};
+use std::path::Path;
+use std::process;
 
+use rand::Rng;
+
+use guessing_game_ext_lib::bulls::{self, BullsGame};
+use guessing_game_ext_lib::clock::{SystemClock, TimedInput};
+use guessing_game_ext_lib::events::EventLog;
+use guessing_game_ext_lib::history::{self, Entry};
+use guessing_game_ext_lib::server::Server;
+use guessing_game_ext_lib::words::{self, WordGame};
+use guessing_game_ext_lib::{config, multiplayer, reverse, script, seeded_rng, solver, terminal, tournament, tui};
+use guessing_game_ext_lib::{Config, Game, Mode, Outcome, Replay, Score, ScoreBoard};
+
+const USAGE: &str = "\
+사용법: guessing_game_ext_lib [옵션]
+       guessing_game_ext_lib stats [--history-file <경로>]
+
+명령:
+  stats                                끝난 게임 기록으로 게임 수, 승률, 평균/중앙값 시도 횟수,
+                                       시도 횟수 분포, 가장 많이 쓴 첫 추측을 출력
+
+옵션:
+  -d, --difficulty <easy|normal|hard>  범위와 시도 횟수를 한 번에 정하는 프리셋
+                                       (easy: 1~50 / 10번, normal: 1~100 / 7번, hard: 1~1000 / 10번)
+      --min <N>                        비밀 숫자의 최솟값 (기본값 1)
+      --max <N>                        비밀 숫자의 최댓값 (기본값 100)
+  -n, --attempts <N>                   최대 시도 횟수 (기본값 무제한)
+      --debug                          비밀 숫자와 남은 후보 구간을 출력 (개발용)
+      --hints                          추측마다 거리(아주 뜨거움/뜨거움/따뜻함/차가움)와
+                                       이전 추측보다 가까워졌는지 알려줌. '힌트'를 입력하면
+                                       기회 하나를 써서 짝수/3의 배수 여부를 한 번 알려줌
+      --tui                            전체 화면 모드: 남은 후보 구간 막대, 지난 추측, 남은 기회와 힌트를
+                                       입력할 때마다 같은 자리에 다시 그림
+                                       (입력이나 출력이 터미널이 아니면 일반 모드로 진행)
+      --guess-time <초>                추측 한 번에 쓸 수 있는 시간. 시간이 지나면 입력을 기다리지 않고 게임이 끝남 (소수 가능)
+      --time-limit <초>                게임 전체에 쓸 수 있는 시간. 시간이 지나면 입력을 기다리지 않고 게임이 끝남
+      --speedrun                       추측마다 지금까지 걸린 시간을 보여주는 스피드런 모드
+                                       (시간 제한이나 스피드런을 켜면 끝날 때 걸린 시간을 출력)
+      --script <경로>                  파일(-이면 표준 입력)에서 추측을 한 줄씩 읽어 안내 없이 한 판을 함.
+                                       빈 줄과 #으로 시작하는 줄은 건너뛰고, 잘못된 줄을 만나면 멈춤.
+                                       점수와 기록 파일에는 남기지 않음 (종료 코드는 아래 참고)
+                                       표준 입력을 파이프로 넣어도 자동으로 켜지지 않으므로
+                                       파이프에서 읽으려면 --script -를 줘야 함
+      --name <이름>                    점수 파일에 남길 플레이어 이름 (기본값 익명)
+      --score-file <경로>              점수 파일 경로 (기본값 scores.txt). 이긴 게임만 기록하고
+                                       진 게임까지 포함한 끝난 게임은 --history-file에 남김
+      --history-file <경로>            끝난 게임(이김/짐)을 남기는 기록 파일 (기본값 history.txt)
+      --scores                         게임 대신 난이도별 상위 기록(이긴 게임)을 출력
+      --top <N>                        --scores에서 난이도별로 보여줄 기록 수 (기본값 10)
+      --solve                          컴퓨터가 이분 탐색으로 게임을 풀고 매 추측을 출력
+      --tournament                     여러 추측 전략(bisection, random, linear, golden)을 겨루게 하고
+                                       평균/최대 시도 횟수를 표로 출력. 한 판에 쓸 수 있는 추측은
+                                       이분 탐색 최악의 경우의 4배이고 그 안에 못 맞추면 실패로 셈
+      --games <N>                      --tournament에서 풀게 할 비밀 숫자 개수 (기본값 10000)
+      --seed <N>                       비밀 숫자를 정하는 시드. 같은 시드면 항상 같은 숫자가 나온다.
+                                       (--tournament에서는 비밀 숫자들과 random 전략의 시드)
+      --record <경로>                  시드와 입력한 줄, 출력 내용을 재생 파일로 저장
+                                       (시간 제한, 스피드런, --script와는 같이 쓸 수 없음)
+      --log <경로>                     게임에서 일어난 일을 JSON Lines 형식으로 기록 (아래 스키마 참고)
+      --replay <경로>                  재생 파일의 게임을 다시 돌리고 원래 출력과 한 글자씩 비교
+                                       (다르면 종료 코드 1)
+      --players <이름,이름,...>        여러 플레이어가 돌아가며 같은 숫자를 맞추고 순위를 매김
+      --setter <이름>                  이 플레이어가 비밀 숫자를 직접 정함 (터미널이면 입력이 화면에 보이지 않음)
+      --server <주소>                  TCP 서버를 열고 접속한 클라이언트마다 게임을 따로 진행
+                                       (예: 127.0.0.1:7878, 클라이언트는 guessing_client <주소>)
+      --bulls                          숫자 야구: 서로 다른 숫자 4개로 된 수를 맞추고 추측마다
+                                       스트라이크(자리까지 맞음)와 볼(숫자만 맞음) 개수를 알려줌
+                                       (--solve와 함께 쓰면 컴퓨터가 풀면서 후보가 줄어드는 과정을 출력)
+      --words                          단어 맞추기: 숨겨진 단어를 맞추고 글자마다 맞음(초록)/있음(노랑)/없음(회색)을
+                                       표시. 한글은 자모로 분해해서 비교 (기본 기회 6번)
+      --word-list <경로>               --words에서 쓸 단어 목록 파일 (한 줄에 한 단어, #은 주석)
+      --reverse                        역할을 바꿔서 플레이어가 생각한 숫자를 컴퓨터가 맞춤
+                                       (모순된 대답을 하면 알려줌)
+  -h, --help                           이 도움말을 출력
+
+stats, --scores, --solve, --tournament, --replay, --players/--setter, --server, --bulls,
+--words/--word-list, --reverse, --script는 게임 방식을 정하므로 하나만 쓸 수 있음
+(--bulls와 --solve는 함께 쓸 수 있음)
+
+--log 기록 형식 (한 줄에 JSON 객체 하나, 필드 순서는 항상 아래와 같음, 버전 1):
+  {\"event\":\"start\",\"version\":1,\"min\":1,\"max\":100,\"max_attempts\":7,\"seed\":1234}
+      max_attempts는 횟수 제한이 없으면 null
+  {\"event\":\"guess\",\"input\":\"50\",\"value\":50}
+  {\"event\":\"guess\",\"input\":\"abc\",\"rejected\":\"not_a_number\",\"message\":\"...\"}
+      input은 입력한 줄에서 앞뒤 공백과 개행을 뺀 것. 거절한 입력은 value 대신 rejected와 message가 있고
+      시도 횟수에 들어가지 않는다. rejected: empty, not_utf8, not_a_number, negative, overflow,
+      out_of_range
+  {\"event\":\"compare\",\"attempt\":1,\"guess\":50,\"ordering\":\"greater\"}
+      ordering은 추측을 비밀 숫자와 비교한 결과: less(너무 작음), greater(너무 큼), equal(맞춤)
+  {\"event\":\"hint\",\"attempt\":2}
+      --hints에서 힌트를 쓴 경우. 힌트도 시도 하나를 쓴다.
+  {\"event\":\"end\",\"outcome\":\"won\",\"attempts\":3,\"duration_ms\":5120}
+      outcome: won, lost(기회를 모두 씀), quit(입력이 끝남), timed_out(제한 시간을 넘김)
+
+--script 종료 코드:
+  0  맞춤    1  기회를 모두 쓰거나 시간 초과    2  잘못된 명령줄 인수
+  3  추측으로 받아들일 수 없는 줄(UTF-8이 아닌 줄 포함)이 있음    4  맞추기 전에 스크립트가 끝남
+
+환경 변수:
+  GUESSING_GAME_DEBUG                  비어 있지 않고 0이 아닌 값이면 --debug와 같다.
+";
+
+/*
+게임 로직은 라이브러리(src/lib.rs, src/game.rs)로 옮겼다.
+바이너리는 명령줄 인수를 설정으로 바꾸고 실제 난수 생성기와 표준 입출력을 게임에 넘겨주기만 한다.
+stdin()과 stdout()은 lock()을 호출하면 BufRead, Write를 구현한 핸들을 돌려준다.
+*/
 fn main() {
-    let rand_number = rand::thread_rng().gen_range(1, 101);
-    loop {
-        println!("숫자를 입력하세여");
-        let mut guess = String::new();
-        io::stdin().read_line(&mut guess).expect("라인 가져오기 실패");
-        println!("[생성된 랜덤 숫자는 {} 입니다.]", rand_number);
-        let guess: u32 = match guess.trim().parse() {
-            Ok(num) => num,
-            Err(_) => continue
-        };
-        println!("님이 입력한 숫자는 {} 입니다.", guess);
-        match guess.cmp(&rand_number) {
-            Ordering::Less      => println!("너무 작음"),
-            Ordering::Greater   => println!("너무 큼"),
-            Ordering::Equal     => {
-                println!("님이 맞춤");
-                break;
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        print!("{}", USAGE);
+        return;
+    }
+    let mut config = match Config::from_args(args) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+    if config::env_flag(env::var_os("GUESSING_GAME_DEBUG").as_deref()) {
+        config.debug = true;
+    }
+
+    match config.mode {
+        Mode::Play => play(config),
+        Mode::Scores { top } => {
+            let board = ScoreBoard::load(&config.score_file).expect("점수 파일 읽기 실패");
+            board.print_top(top, io::stdout().lock()).expect("입출력 실패");
+        }
+        Mode::Stats => history::print_report(&config.history_file, io::stdout().lock()).expect("기록 파일 읽기 실패"),
+        Mode::Solve => {
+            let mut game = Game::with_rng(&mut seeded_rng(seed(&config)), config);
+            solver::play(&mut game, io::stdout().lock()).expect("입출력 실패");
+        }
+        Mode::Tournament { games } => {
+            let seed = config.seed.unwrap_or(tournament::DEFAULT_SEED);
+            let mut strategies = tournament::builtin_strategies(seed);
+            let standings = tournament::run(&mut strategies, &config, games, seed);
+            tournament::print_table(&standings, io::stdout().lock()).expect("입출력 실패");
+        }
+        Mode::Reverse => {
+            let stdin = io::stdin();
+            reverse::play(config.min, config.max, stdin.lock(), io::stdout().lock()).expect("입출력 실패");
+        }
+        Mode::Replay { ref path } => replay(path),
+        Mode::Bulls { solve } => {
+            let mut game = BullsGame::with_rng(&mut seeded_rng(seed(&config)), config);
+            if solve {
+                bulls::play_solver(&mut game, io::stdout().lock()).expect("입출력 실패");
+            } else {
+                let stdin = io::stdin();
+                game.play(stdin.lock(), io::stdout().lock()).expect("입출력 실패");
             }
         }
+        Mode::Multiplayer { ref players, ref setter } => {
+            let stdin = io::stdin();
+            let stdout = io::stdout();
+            let secret = match setter {
+                Some(setter) => {
+                    let hidden = terminal::hide_input();
+                    let secret = multiplayer::read_secret(setter, &config, hidden.is_some(), stdin.lock(), stdout.lock());
+                    if let Some(guard) = hidden {
+                        drop(guard);
+                        // 에코가 꺼진 동안 입력한 엔터가 화면에 찍히지 않았으므로 줄을 바꿔준다.
+                        println!();
+                    }
+                    match secret.expect("입출력 실패") {
+                        Some(secret) => secret,
+                        None => return,
+                    }
+                }
+                None => Game::with_rng(&mut seeded_rng(seed(&config)), config.clone()).secret(),
+            };
+            multiplayer::play(secret, &config, players, stdin.lock(), stdout.lock()).expect("입출력 실패");
+        }
+        Mode::Words { ref list } => {
+            let list = match list {
+                Some(path) => match words::load_words(path) {
+                    Ok(list) => list,
+                    Err(error) => {
+                        eprintln!("단어 목록을 읽을 수 없습니다: {} ({})", path.display(), error);
+                        process::exit(2);
+                    }
+                },
+                None => words::BUILTIN_WORDS.iter().map(|word| word.to_string()).collect(),
+            };
+            let stdin = io::stdin();
+            let stdout = io::stdout();
+            // 파이프로 내보낼 때는 ANSI 색 코드 대신 괄호로 표시한다.
+            let color = stdout.is_terminal();
+            let mut game = WordGame::with_rng(&mut seeded_rng(seed(&config)), &list, config.clone()).color(color);
+            game.play(stdin.lock(), stdout.lock()).expect("입출력 실패");
+        }
+        Mode::Server { ref addr } => {
+            let server = match Server::bind(addr, config.clone(), seed(&config)) {
+                Ok(server) => server,
+                Err(error) => {
+                    eprintln!("{} 에서 서버를 열 수 없습니다 ({})", addr, error);
+                    process::exit(2);
+                }
+            };
+            println!("{} 에서 접속을 기다립니다.", server.local_addr().expect("주소 확인 실패"));
+            server.run().expect("서버 실행 실패");
+        }
     }
-    /*
-    cmp 메소드는 비교가 가능한 모든것들에 대해 호출할 수 있다고 한다. cmp 메소드는 비교하고 싶은 것들의 참조자를 받는다.
-    cmp는 Ordering 타입의 열거형을 리턴하기 때문에 use std::cmp::Ordering;을 사용하였다.
-    match 표현문을 이용해 Ordering의 값에 따라 무엇을 할지 정할수 있다.
+}
+
+/// 시드를 주지 않았으면 무작위로 하나 뽑는다. 어느 쪽이든 시드가 있어야 재생 파일에 남길 수 있다.
+fn seed(config: &Config) -> u32 {
+    config.seed.unwrap_or_else(|| rand::thread_rng().gen())
+}
 
-    match 표현식은 arm으로 이루어져 있다고 한다.
-    하나의 arm은 하나의 패펀과 match 표현식에서 주어진 값이 패턴과 맞을때 실행할 코드로 이루어져 있다.
-    match에 대한 것은 추후에 자세히 다룬다.
+fn replay(path: &std::path::Path) {
+    let replay = match Replay::load(path) {
+        Ok(replay) => replay,
+        Err(error) => {
+            eprintln!("재생 파일을 읽을 수 없습니다: {} ({})", path.display(), error);
+            process::exit(2);
+        }
+    };
+    let (_, transcript) = replay.run().expect("입출력 실패");
+    print!("{}", transcript);
+    match replay.first_difference(&transcript) {
+        None => println!("-- 재생 결과가 원래 기록과 같습니다. (시드 {})", replay.seed),
+        Some((line, original, replayed)) => {
+            println!("-- 재생 결과가 {}번째 줄부터 원래 기록과 다릅니다.", line);
+            println!("   원래: {}", original.as_deref().unwrap_or("(끝)"));
+            println!("   재생: {}", replayed.as_deref().unwrap_or("(끝)"));
+            process::exit(1);
+        }
+    }
+}
 
-    러스트는 정적 타입 시스템을 가지고 있지만
-        - 8줄, 10줄은 각 타입이 명확하기 때문에 타입을 적지 않는다.
-    타입을 추론이 필요할 경우 동적으로 추론한다.
-    하지만 위 stdin을 통해 입력한 데이터는 문자열 형이고 랜덤하게 생성한 데이터는 숫자이다. 그래서 별도로 형변환을 해야 한다.
+fn play(config: Config) {
+    let seed = seed(&config);
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let log = config.log.as_ref().map(|path| match File::create(path) {
+        Ok(file) => EventLog::new(file),
+        Err(error) => {
+            eprintln!("이벤트 기록 파일을 만들 수 없습니다: {} ({})", path.display(), error);
+            process::exit(2);
+        }
+    });
+    let mut game = Game::with_rng(&mut seeded_rng(seed), Config { seed: Some(seed), ..config.clone() });
+    if let Some(log) = log {
+        game = game.with_log(log);
+    }
+    if let Some(ref path) = config.script {
+        run_script(&mut game, path);
+    }
+    // 시간 제한이 있으면 입력을 기다리는 동안에도 시간이 지났는지 볼 수 있도록 다른 스레드에서 읽는다.
+    let input: Box<dyn BufRead> = if config.guess_time.is_some() || config.time_limit.is_some() {
+        let clock = SystemClock::new();
+        game = game.with_clock(clock);
+        Box::new(TimedInput::new(BufReader::new(io::stdin()), clock, game.deadline()))
+    } else {
+        Box::new(stdin.lock())
+    };
+    let outcome = match config.record {
+        Some(ref path) => {
+            let (outcome, replay) =
+                Replay::record_game(seed, &mut game, input, stdout.lock()).expect("입출력 실패");
+            if let Err(error) = replay.save(path) {
+                eprintln!("재생 파일을 저장하지 못했습니다 ({})", error);
+            }
+            outcome
+        }
+        // 파이프나 스크립트로 입력을 주는 경우에는 화면을 지우는 코드가 섞이지 않도록 일반 모드로 한다.
+        None if config.tui && stdin.is_terminal() && stdout.is_terminal() => {
+            tui::play(&mut game, input, stdout.lock()).expect("입출력 실패")
+        }
+        None => game.play(input, stdout.lock()).expect("입출력 실패"),
+    };
 
-    문자열 타입 guess는 trim, parse 메소드를 가지고 있다.
-    trim 메소드는 처음과 끝의 빈칸 혹은 개행을 제거한다.
-    parse 메소드는 문자열을 숫자로 파싱한다. 하지만 이 메소드는 다양한 형태의 숫자로 반환할 수 있으므로 타입을 명시할 필요가 있다.
-    이 예제에선 변수를 선언할 때 타입을 정하는 것으로 하였다. 변수명 뒤 콜론은 변수의 타입을 명시한 것이다. (unsigned 32bit)
+    if let Some(entry) = Entry::of(&game, &config, outcome, game.elapsed()) {
+        if let Err(error) = history::append(&config.history_file, &entry) {
+            eprintln!("게임 기록을 저장하지 못했습니다 ({})", error);
+        }
+    }
 
-    parse 메소드는 예외가 발생할 가능성이 높다. 문자열 내 숫자가 있지 않을 가능성도 있기 때문이다.
-    parse 메소드의 리턴값도 실패할 경우를 위해 io:Result 타입을 리턴해준다.
-    그래서 expect 메소드를 실행해도 되고 match 표현식으로 처리해도 된다. (보통 match 표현식으로 처리한다.)
-    저 이상한 모양새가 동작할 수 있는 이유는 parse 메소드가 오류가 발생하지 않았다면 Result 타입은 Ok를 반환하고 이어서 num을 guess에 넣는다.
-    Err과 매칭된다면 (인수의 언더바는 모든값과 매칭될 수 있다. 따라서 모든 에러를 핸들링할 수 있다.) continue 키워드가 동작되 루프의 처음으로 돌아간다.
-    
-    loop 키워드는 while (1)과 동일하다. 내부에서 break 키워드로 빠져나오기 전까지 무한반복을 수행한다. continue 키워드를 쓸수도 있다.
+    // 점수 파일에 기록하지 못했다고 이미 끝난 게임을 실패로 만들 필요는 없으므로 경고만 출력한다.
+    if let Outcome::Won(attempts) = outcome {
+        let score = Score::new(&config.name, &config, attempts, game.elapsed());
+        if let Err(error) = ScoreBoard::append(&config.score_file, &score) {
+            eprintln!("점수를 저장하지 못했습니다 ({})", error);
+        }
+    }
+}
 
-    결과적으로 우리는 let, match, 메소드, 연관함수, 외부 크레이트와 같이 러스트의 기능들을 사용해 보았다.
-    */
+/// 스크립트로 한 판을 하고 결과에 맞는 종료 코드로 끝낸다.
+/// 테스트용으로 돌리는 게임이므로 점수와 기록 파일에는 남기지 않는다.
+fn run_script(game: &mut Game, path: &Path) -> ! {
+    let stdout = io::stdout();
+    let result = if path == Path::new("-") {
+        let stdin = io::stdin();
+        script::run(game, stdin.lock(), stdout.lock())
+    } else {
+        match File::open(path) {
+            Ok(file) => script::run(game, BufReader::new(file), stdout.lock()),
+            Err(error) => {
+                eprintln!("스크립트 파일을 열 수 없습니다: {} ({})", path.display(), error);
+                process::exit(2);
+            }
+        }
+    };
+    process::exit(result.expect("입출력 실패").exit_code());
 }
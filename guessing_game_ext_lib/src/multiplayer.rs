@@ -0,0 +1,160 @@
+use std::io::{self, BufRead, Write};
+
+use prompt::{Prompt, PromptError};
+
+use crate::config::Config;
+use crate::game::{Game, Outcome};
+use crate::input::parse_guess;
+
+/// 한 플레이어의 최종 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking {
+    pub name: String,
+    pub outcome: Outcome,
+}
+
+/// 여러 플레이어가 한 자리에서 돌아가며 같은 비밀 숫자를 맞춘다. (hot-seat)
+///
+/// 플레이어마다 따로 Game을 가지고 있어서 시도 횟수와 남은 기회를 각자 센다.
+/// 맞췄거나 기회를 다 쓴 플레이어는 차례에서 빠지고, 모두 끝나면 적은 시도로 맞춘 순서대로 순위를 매긴다.
+/// 시도 횟수가 같으면 먼저 맞춘 사람이 앞선다.
+pub fn play<R: BufRead, W: Write>(
+    secret: u32,
+    config: &Config,
+    names: &[String],
+    mut input: R,
+    mut output: W,
+) -> io::Result<Vec<Ranking>> {
+    let mut games: Vec<Game> = names.iter().map(|_| Game::with_config(secret, config.clone())).collect();
+    // 끝난 순서대로 (플레이어 번호, 결과)
+    let mut finished: Vec<(usize, Outcome)> = Vec::new();
+
+    writeln!(output, "{} 님이 차례대로 같은 숫자를 맞춥니다.", names.join(", "))?;
+    if let Some(game) = games.first() {
+        game.intro(&mut output)?;
+    }
+    'rounds: while finished.len() < names.len() {
+        for (player, game) in games.iter_mut().enumerate() {
+            if finished.iter().any(|&(p, _)| p == player) {
+                continue;
+            }
+            writeln!(output, "=== {} 님 차례 ({}번째 시도) ===", names[player], game.attempts() + 1)?;
+            match game.turn(&mut input, &mut output)? {
+                None => (),
+                Some(Outcome::Quit) => break 'rounds,
+                Some(outcome) => {
+                    if let Outcome::Won(attempts) = outcome {
+                        writeln!(output, "{} 님이 {}번 만에 맞췄습니다!", names[player], attempts)?;
+                    }
+                    finished.push((player, outcome));
+                }
+            }
+        }
+    }
+
+    let mut rankings: Vec<Ranking> = finished
+        .iter()
+        .map(|&(player, outcome)| Ranking { name: names[player].clone(), outcome })
+        .collect();
+    // 입력이 끝나서 중간에 그만둔 플레이어는 맨 뒤에 둔다.
+    for (player, name) in names.iter().enumerate() {
+        if !finished.iter().any(|&(p, _)| p == player) {
+            rankings.push(Ranking { name: name.clone(), outcome: Outcome::Quit });
+        }
+    }
+    // sort_by_key는 안정 정렬이므로 같은 시도 횟수끼리는 먼저 끝난 순서가 유지된다.
+    rankings.sort_by_key(|ranking| match ranking.outcome {
+        Outcome::Won(attempts) => (0, attempts),
+        Outcome::Lost | Outcome::TimedOut => (1, 0),
+        Outcome::Quit => (2, 0),
+    });
+    print_rankings(&rankings, &mut output)?;
+    Ok(rankings)
+}
+
+pub fn print_rankings<W: Write>(rankings: &[Ranking], mut output: W) -> io::Result<()> {
+    writeln!(output, "=== 최종 순위 ===")?;
+    let mut rank = 0;
+    for ranking in rankings {
+        match ranking.outcome {
+            Outcome::Won(attempts) => {
+                rank += 1;
+                writeln!(output, "{}. {} - {}번 만에 맞춤", rank, ranking.name, attempts)?;
+            }
+            Outcome::Lost => writeln!(output, "-  {} - 기회를 모두 씀", ranking.name)?,
+            Outcome::TimedOut => writeln!(output, "-  {} - 시간 초과", ranking.name)?,
+            Outcome::Quit => writeln!(output, "-  {} - 끝내지 못함", ranking.name)?,
+        }
+    }
+    Ok(())
+}
+
+/// 출제자가 비밀 숫자를 입력한다. 범위 밖이거나 숫자가 아니면 다시 묻는다.
+/// 입력한 숫자가 화면에 보이지 않게 하는 것은 부르는 쪽에서 터미널 에코를 꺼서 처리한다. (terminal::hide_input)
+/// hidden은 에코를 실제로 껐는지이다. 끄지 못했으면 입력이 보인다고 알려서 다른 사람이 보지 않게 한다.
+pub fn read_secret<R: BufRead, W: Write>(setter: &str, config: &Config, hidden: bool, input: R, output: W) -> io::Result<Option<u32>> {
+    let notice = if hidden { "(화면에 보이지 않습니다)" } else { "(주의: 입력한 숫자가 화면에 보입니다)" };
+    let message = format!("{} 님, {} 부터 {} 사이의 비밀 숫자를 입력하세요. {}", setter, config.min, config.max, notice);
+    match Prompt::new(input, output).ask_with(&message, |line| parse_guess(line, config.min, config.max)) {
+        Ok(secret) => Ok(Some(secret)),
+        Err(PromptError::Eof) => Ok(None),
+        Err(PromptError::Io(error)) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn players_take_turns_and_are_ranked() {
+        let mut output = Vec::new();
+        // 철수: 50, 25, 42 / 영희: 40, 42 / 민수: 1, 2, 3 (기회 3번)
+        let input = &b"50\n40\n1\n25\n42\n2\n42\n3\n"[..];
+        let config = Config { max_attempts: Some(3), ..Config::default() };
+        let rankings = play(42, &config, &names(&["철수", "영희", "민수"]), input, &mut output).unwrap();
+
+        assert_eq!(
+            rankings,
+            vec![
+                Ranking { name: "영희".to_string(), outcome: Outcome::Won(2) },
+                Ranking { name: "철수".to_string(), outcome: Outcome::Won(3) },
+                Ranking { name: "민수".to_string(), outcome: Outcome::Lost },
+            ]
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("=== 영희 님 차례 (2번째 시도) ==="));
+        assert!(output.contains("1. 영희 - 2번 만에 맞춤\n2. 철수 - 3번 만에 맞춤\n-  민수 - 기회를 모두 씀\n"));
+    }
+
+    #[test]
+    fn unfinished_players_on_eof() {
+        let rankings = play(42, &Config::default(), &names(&["a", "b"]), &b"42\n"[..], io::sink()).unwrap();
+        assert_eq!(rankings[0].outcome, Outcome::Won(1));
+        assert_eq!(rankings[1].outcome, Outcome::Quit);
+    }
+
+    #[test]
+    fn setter_is_asked_again() {
+        let mut output = Vec::new();
+        let secret = read_secret("출제자", &Config::default(), true, &b"abc\n500\n77\n"[..], &mut output).unwrap();
+        assert_eq!(secret, Some(77));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("500은(는) 범위 밖입니다."));
+        assert!(output.contains("(화면에 보이지 않습니다)"));
+    }
+
+    #[test]
+    fn setter_is_warned_when_echo_stays_on() {
+        let mut output = Vec::new();
+        let secret = read_secret("출제자", &Config::default(), false, &b"77\n"[..], &mut output).unwrap();
+        assert_eq!(secret, Some(77));
+        let output = String::from_utf8(output).unwrap();
+        assert!(!output.contains("(화면에 보이지 않습니다)"));
+        assert!(output.contains("(주의: 입력한 숫자가 화면에 보입니다)"));
+    }
+}
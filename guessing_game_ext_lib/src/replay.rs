@@ -0,0 +1,290 @@
+use std::fs;
+use std::io::{self, BufRead, ErrorKind, Read, Write};
+use std::path::Path;
+
+use crate::config::Config;
+use crate::game::{seeded_rng, Game, Outcome};
+
+/// 재생 파일의 첫 줄. 형식이 바뀌면 숫자를 올린다.
+const HEADER: &str = "# guessing_game replay v1";
+
+/// 한 판의 게임을 그대로 다시 돌려볼 수 있도록 남긴 기록.
+///
+/// 파일은 한 줄에 항목 하나씩 쓰는 텍스트 형식이다.
+///
+/// ```text
+/// # guessing_game replay v1
+/// seed 1234
+/// min 1
+/// max 100
+/// attempts -
+/// debug false
+/// hints false
+/// in 50\n
+/// in 25\r\n
+/// out 1 부터 100 사이의 숫자를 맞춰보세요.
+/// out ...
+/// ```
+///
+/// `in`은 플레이어가 입력한 줄, `out`은 원래 게임이 출력한 줄이다. attempts가 `-`이면 횟수 제한이 없다.
+/// 입력은 읽은 바이트를 그대로 다시 넣을 수 있도록 줄 끝(`\n`, `\r\n`)까지 남기고
+/// 파일에는 `\`, `\r`, `\n`을 이스케이프해서 쓴다. 마지막 줄에 줄 끝이 없었으면 `\n` 없이 남는다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    pub seed: u32,
+    pub config: Config,
+    /// 게임이 읽은 입력을 줄 끝까지 포함해서 한 줄씩 나눈 것
+    pub inputs: Vec<String>,
+    pub transcript: String,
+}
+
+/// 읽은 내용을 그대로 복사해두는 입력 핸들
+struct TeeReader<'a, R> {
+    inner: R,
+    copy: &'a mut Vec<u8>,
+}
+
+impl<'a, R: Read> Read for TeeReader<'a, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.copy.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+}
+
+impl<'a, R: BufRead> BufRead for TeeReader<'a, R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        // 게임이 실제로 소비한 바이트만 복사한다. fill_buf로 미리 읽어둔 나머지는 기록하지 않는다.
+        if let Ok(buf) = self.inner.fill_buf() {
+            let amt = amt.min(buf.len());
+            self.copy.extend_from_slice(&buf[..amt]);
+        }
+        self.inner.consume(amt);
+    }
+}
+
+/// 쓴 내용을 원래 출력과 복사본 양쪽에 쓰는 출력 핸들
+struct TeeWriter<'a, W> {
+    inner: W,
+    copy: &'a mut Vec<u8>,
+}
+
+impl<'a, W: Write> Write for TeeWriter<'a, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.copy.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl Replay {
+    /// 시드로 비밀 숫자를 정해서 게임을 한 판 하고, 입력한 줄과 출력된 내용을 함께 기록한다.
+    /// 설정 중에서는 게임 진행에 영향을 주는 범위, 시도 횟수, 디버그와 힌트 여부만 남긴다.
+    pub fn record<R: BufRead, W: Write>(seed: u32, config: Config, input: R, output: W) -> io::Result<(Outcome, Replay)> {
+        let mut game = Game::with_rng(&mut seeded_rng(seed), config);
+        Replay::record_game(seed, &mut game, input, output)
+    }
+
+    /// 이미 만든 게임을 한 판 하면서 기록한다. game은 seed로 비밀 숫자를 뽑은 게임이어야 한다.
+    /// 이벤트 기록(events.rs)을 붙이거나 끝난 뒤 게임 상태를 보고 싶을 때 쓴다.
+    pub fn record_game<R: BufRead, W: Write>(seed: u32, game: &mut Game, input: R, output: W) -> io::Result<(Outcome, Replay)> {
+        let config = game.config();
+        let config = Config {
+            min: config.min,
+            max: config.max,
+            max_attempts: config.max_attempts,
+            debug: config.debug,
+            hints: config.hints,
+            ..Config::default()
+        };
+        let mut read = Vec::new();
+        let mut written = Vec::new();
+        let outcome = game.play(
+            TeeReader { inner: input, copy: &mut read },
+            TeeWriter { inner: output, copy: &mut written },
+        )?;
+        let replay = Replay {
+            seed,
+            config,
+            inputs: String::from_utf8_lossy(&read).split_inclusive('\n').map(String::from).collect(),
+            transcript: String::from_utf8_lossy(&written).into_owned(),
+        };
+        Ok((outcome, replay))
+    }
+
+    /// 기록된 시드와 입력으로 게임을 다시 돌리고 새로 출력된 내용을 돌려준다.
+    pub fn run(&self) -> io::Result<(Outcome, String)> {
+        let mut game = Game::with_rng(&mut seeded_rng(self.seed), self.config.clone());
+        let input = self.inputs.concat();
+        let mut output = Vec::new();
+        let outcome = game.play(input.as_bytes(), &mut output)?;
+        Ok((outcome, String::from_utf8_lossy(&output).into_owned()))
+    }
+
+    /// 다시 돌린 결과가 원래 기록과 다른 첫 줄을 찾는다. 완전히 같으면 None 이다.
+    /// 돌려주는 값은 (줄 번호, 원래 줄, 새 줄)이고 한쪽이 먼저 끝났으면 그쪽은 None 이다.
+    pub fn first_difference(&self, replayed: &str) -> Option<(usize, Option<String>, Option<String>)> {
+        if self.transcript == replayed {
+            return None;
+        }
+        let mut original = self.transcript.lines();
+        let mut replayed = replayed.lines();
+        let mut number = 1;
+        loop {
+            match (original.next(), replayed.next()) {
+                (Some(a), Some(b)) if a == b => number += 1,
+                (None, None) => return Some((number, None, None)),
+                (a, b) => return Some((number, a.map(String::from), b.map(String::from))),
+            }
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        text.push_str(HEADER);
+        text.push('\n');
+        text.push_str(&format!("seed {}\n", self.seed));
+        text.push_str(&format!("min {}\n", self.config.min));
+        text.push_str(&format!("max {}\n", self.config.max));
+        match self.config.max_attempts {
+            Some(attempts) => text.push_str(&format!("attempts {}\n", attempts)),
+            None => text.push_str("attempts -\n"),
+        }
+        text.push_str(&format!("debug {}\n", self.config.debug));
+        text.push_str(&format!("hints {}\n", self.config.hints));
+        for line in &self.inputs {
+            text.push_str(&format!("in {}\n", escape(line)));
+        }
+        for line in self.transcript.lines() {
+            text.push_str(&format!("out {}\n", line));
+        }
+        text
+    }
+
+    /// 재생 파일 내용을 읽는다. 형식이 맞지 않으면 ErrorKind::InvalidData 에러를 돌려준다.
+    pub fn parse(text: &str) -> io::Result<Replay> {
+        let mut lines = text.lines();
+        if lines.next() != Some(HEADER) {
+            return Err(invalid(1, "재생 파일 머리말이 없습니다"));
+        }
+        let mut seed = None;
+        let mut config = Config::default();
+        let mut inputs = Vec::new();
+        let mut transcript = String::new();
+        for (i, line) in lines.enumerate() {
+            let number = i + 2;
+            let (key, value) = match line.find(' ') {
+                Some(space) => (&line[..space], &line[space + 1..]),
+                None => (line, ""),
+            };
+            match key {
+                "seed" => seed = Some(value.parse().map_err(|_| invalid(number, "시드가 숫자가 아닙니다"))?),
+                "min" => config.min = value.parse().map_err(|_| invalid(number, "min이 숫자가 아닙니다"))?,
+                "max" => config.max = value.parse().map_err(|_| invalid(number, "max가 숫자가 아닙니다"))?,
+                "attempts" if value == "-" => config.max_attempts = None,
+                "attempts" => {
+                    config.max_attempts = Some(value.parse().map_err(|_| invalid(number, "attempts가 숫자가 아닙니다"))?)
+                }
+                "debug" => config.debug = value.parse().map_err(|_| invalid(number, "debug는 true나 false여야 합니다"))?,
+                "hints" => config.hints = value.parse().map_err(|_| invalid(number, "hints는 true나 false여야 합니다"))?,
+                "in" => inputs.push(unescape(value).ok_or_else(|| invalid(number, "잘못된 이스케이프입니다"))?),
+                "out" => {
+                    transcript.push_str(value);
+                    transcript.push('\n');
+                }
+                _ => return Err(invalid(number, "알 수 없는 항목입니다")),
+            }
+        }
+        let seed = seed.ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "재생 파일에 시드가 없습니다"))?;
+        config
+            .validate()
+            .map_err(|error| io::Error::new(ErrorKind::InvalidData, error.to_string()))?;
+        Ok(Replay { seed, config, inputs, transcript })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Replay> {
+        Replay::parse(&fs::read_to_string(path)?)
+    }
+}
+
+/// 입력 한 줄을 재생 파일의 한 줄에 들어가도록 바꾼다.
+fn escape(line: &str) -> String {
+    line.replace('\\', "\\\\").replace('\r', "\\r").replace('\n', "\\n")
+}
+
+fn unescape(text: &str) -> Option<String> {
+    let mut line = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            line.push(c);
+            continue;
+        }
+        match chars.next()? {
+            '\\' => line.push('\\'),
+            'r' => line.push('\r'),
+            'n' => line.push('\n'),
+            _ => return None,
+        }
+    }
+    Some(line)
+}
+
+fn invalid(line: usize, message: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, format!("재생 파일 {}번째 줄: {}", line, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_game_replays_identically() {
+        let config = Config { max_attempts: Some(5), ..Config::default() };
+        let mut shown = Vec::new();
+        let (outcome, replay) = Replay::record(7, config, &b"50\nabc\\\r\n25"[..], &mut shown).unwrap();
+
+        assert_eq!(replay.inputs, vec!["50\n", "abc\\\r\n", "25"]);
+        assert!(replay.to_text().contains("\nin abc\\\\\\r\\n\nin 25\nout "));
+        assert_eq!(replay.transcript.as_bytes(), &shown[..]);
+
+        let parsed = Replay::parse(&replay.to_text()).unwrap();
+        assert_eq!(parsed, replay);
+        let (replayed_outcome, transcript) = parsed.run().unwrap();
+        assert_eq!(replayed_outcome, outcome);
+        assert_eq!(parsed.first_difference(&transcript), None);
+    }
+
+    #[test]
+    fn difference_is_reported() {
+        let (_, replay) = Replay::record(7, Config::default(), &b"50\n"[..], io::sink()).unwrap();
+        let mut changed = replay.transcript.replace("50", "51");
+        let (line, original, _) = replay.first_difference(&changed).unwrap();
+        assert_eq!(line, 3);
+        assert_eq!(original.as_deref(), Some("님이 입력한 숫자는 50 입니다."));
+
+        changed = replay.transcript.clone() + "더 있음\n";
+        assert_eq!(replay.first_difference(&changed).unwrap().1, None);
+    }
+
+    #[test]
+    fn broken_file_is_rejected() {
+        assert_eq!(Replay::parse("seed 1\n").unwrap_err().kind(), ErrorKind::InvalidData);
+        assert!(Replay::parse(&format!("{}\nseed x\n", HEADER)).is_err());
+        assert!(Replay::parse(&format!("{}\nmin 1\n", HEADER)).is_err());
+        assert!(Replay::parse(&format!("{}\nseed 1\nmin 5\nmax 5\n", HEADER)).is_err());
+        assert!(Replay::parse(&format!("{}\nseed 1\nin 5\\t\n", HEADER)).is_err());
+    }
+}
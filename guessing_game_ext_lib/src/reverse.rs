@@ -0,0 +1,162 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+use prompt::{Prompt, PromptError};
+
+use crate::solver::{midpoint, Probe};
+
+/// 플레이어의 대답 한 줄을 Ordering으로 바꾼다.
+/// 컴퓨터의 추측을 기준으로 하므로 "높음"(생각한 숫자가 더 크다)은 추측이 작다는 뜻의 Less 이다.
+pub fn parse_answer(answer: &str) -> Option<Ordering> {
+    match answer.trim().to_lowercase().as_str() {
+        "높음" | "높아" | "up" | "higher" | "h" | "+" => Some(Ordering::Less),
+        "낮음" | "낮아" | "down" | "lower" | "l" | "-" => Some(Ordering::Greater),
+        "맞음" | "정답" | "correct" | "c" | "=" => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+/// 서로 맞지 않는 대답. 생각한 숫자가 `above`보다 크고 `below`보다 작다고 했는데 그런 숫자가 범위 안에 없다.
+/// 범위의 끝 때문에 모순이 생겼다면 해당 쪽은 None 이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contradiction {
+    pub above: Option<u32>,
+    pub below: Option<u32>,
+    pub min: u32,
+    pub max: u32,
+}
+
+impl fmt::Display for Contradiction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.above, self.below) {
+            (Some(above), Some(below)) => {
+                write!(f, "{}보다 크다고 했는데 {}보다 작다고도 했습니다.", above, below)
+            }
+            (Some(above), None) => {
+                write!(f, "{}보다 크다고 했는데 범위의 최댓값은 {} 입니다.", above, self.max)
+            }
+            (None, Some(below)) => {
+                write!(f, "{}보다 작다고 했는데 범위의 최솟값은 {} 입니다.", below, self.min)
+            }
+            (None, None) => write!(f, "대답이 서로 맞지 않습니다."),
+        }
+    }
+}
+
+/// 지금까지의 대답과 모순되지 않는 구간을 계산한다. 그런 숫자가 하나도 없으면 모순된 대답을 찾아 돌려준다.
+pub fn consistent_interval(min: u32, max: u32, history: &[Probe]) -> Result<(u32, u32), Contradiction> {
+    // 가장 빡빡한 아래쪽 경계(가장 큰 "높음" 대답)와 위쪽 경계(가장 작은 "낮음" 대답)만 보면 된다.
+    let above = history.iter().filter(|p| p.ordering == Ordering::Less).map(|p| p.guess).max();
+    let below = history.iter().filter(|p| p.ordering == Ordering::Greater).map(|p| p.guess).min();
+    let mut low = u64::from(min);
+    let mut high = u64::from(max);
+    if let Some(above) = above {
+        low = low.max(u64::from(above) + 1);
+    }
+    if let Some(below) = below {
+        high = high.min(u64::from(below).saturating_sub(1));
+        if below == 0 {
+            return Err(Contradiction { above, below: Some(below), min, max });
+        }
+    }
+    if low > high {
+        return Err(Contradiction { above, below, min, max });
+    }
+    Ok((low as u32, high as u32))
+}
+
+/// 역할을 바꾼 게임이 어떻게 끝났는지
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReverseOutcome {
+    /// 컴퓨터가 숫자를 맞췄다. (숫자, 추측 횟수)
+    Found(u32, u32),
+    /// 플레이어의 대답이 서로 맞지 않았다.
+    Contradiction(Contradiction),
+    /// 숫자를 맞추기 전에 입력이 끝났다.
+    Quit,
+}
+
+/// 플레이어가 min ~ max 사이의 숫자를 생각하고, 컴퓨터가 이분 탐색으로 추측한다.
+/// 대답끼리 모순이 생기면 끝없이 묻는 대신 어느 대답이 맞지 않는지 알려주고 끝낸다.
+pub fn play<R: BufRead, W: Write>(min: u32, max: u32, mut input: R, mut output: W) -> io::Result<ReverseOutcome> {
+    writeln!(output, "{} 부터 {} 사이의 숫자를 하나 생각하세요. 컴퓨터가 맞춰보겠습니다.", min, max)?;
+    writeln!(output, "생각한 숫자가 더 크면 '높음', 더 작으면 '낮음', 맞으면 '맞음'이라고 대답하세요.")?;
+    let mut history = Vec::new();
+    loop {
+        let (low, high) = match consistent_interval(min, max, &history) {
+            Ok(interval) => interval,
+            Err(contradiction) => {
+                writeln!(output, "거짓말이죠? {}", contradiction)?;
+                return Ok(ReverseOutcome::Contradiction(contradiction));
+            }
+        };
+        let guess = midpoint(low, high);
+        let question = format!("{}번째 추측: {} 입니까?", history.len() + 1, guess);
+        let answer = Prompt::new(&mut input, &mut output)
+            .ask_with(&question, |answer| parse_answer(answer).ok_or("'높음', '낮음', '맞음' 중 하나로 대답해주세요."));
+        let ordering = match answer {
+            Ok(ordering) => ordering,
+            Err(PromptError::Eof) => return Ok(ReverseOutcome::Quit),
+            Err(PromptError::Io(error)) => return Err(error),
+        };
+        history.push(Probe { guess, ordering });
+        if ordering == Ordering::Equal {
+            writeln!(output, "{}번 만에 맞췄습니다!", history.len())?;
+            return Ok(ReverseOutcome::Found(guess, history.len() as u32));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe(guess: u32, ordering: Ordering) -> Probe {
+        Probe { guess, ordering }
+    }
+
+    #[test]
+    fn answers() {
+        assert_eq!(parse_answer("높음\n"), Some(Ordering::Less));
+        assert_eq!(parse_answer(" Lower "), Some(Ordering::Greater));
+        assert_eq!(parse_answer("맞음"), Some(Ordering::Equal));
+        assert_eq!(parse_answer("글쎄"), None);
+    }
+
+    #[test]
+    fn contradiction_between_answers() {
+        let history = [probe(45, Ordering::Less), probe(40, Ordering::Greater)];
+        let contradiction = consistent_interval(1, 100, &history).unwrap_err();
+        assert_eq!(contradiction.above, Some(45));
+        assert_eq!(contradiction.below, Some(40));
+        assert_eq!(contradiction.to_string(), "45보다 크다고 했는데 40보다 작다고도 했습니다.");
+    }
+
+    #[test]
+    fn contradiction_with_range() {
+        let contradiction = consistent_interval(1, 100, &[probe(100, Ordering::Less)]).unwrap_err();
+        assert_eq!(contradiction.to_string(), "100보다 크다고 했는데 범위의 최댓값은 100 입니다.");
+        assert!(consistent_interval(0, 10, &[probe(0, Ordering::Greater)]).is_err());
+    }
+
+    #[test]
+    fn honest_player() {
+        let mut output = Vec::new();
+        // 생각한 숫자는 30: 50 -> 낮음, 25 -> 높음, 37 -> 낮음, 31 -> 낮음, 28 -> 높음, 29 -> 높음, 30 -> 맞음
+        let input = "낮음\nh\nl\nl\nh\nh\nc\n".as_bytes();
+        assert_eq!(play(1, 100, input, &mut output).unwrap(), ReverseOutcome::Found(30, 7));
+    }
+
+    #[test]
+    fn lying_player_is_caught() {
+        let mut output = Vec::new();
+        // 50보다 작다고 했다가 계속 크다고 하면 구간이 비어버린다.
+        let outcome = play(1, 100, &b"l\nh\nh\nh\nh\nh\nh\nh\n"[..], &mut output).unwrap();
+        match outcome {
+            ReverseOutcome::Contradiction(c) => assert_eq!((c.above, c.below), (Some(49), Some(50))),
+            other => panic!("모순을 찾지 못함: {:?}", other),
+        }
+        assert!(String::from_utf8(output).unwrap().contains("거짓말이죠?"));
+    }
+}
@@ -0,0 +1,266 @@
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, ErrorKind, Read, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::config::{Config, Difficulty};
+
+/// 점수 파일을 지정하지 않았을 때 사용하는 경로 (현재 디렉토리 기준)
+pub const DEFAULT_SCORE_FILE: &str = "scores.txt";
+
+/// --name을 주지 않았거나 빈 이름일 때 점수 파일에 남기는 이름
+pub const DEFAULT_NAME: &str = "익명";
+
+/// 프리셋과 일치하지 않는 설정으로 한 게임이 묶이는 분류 이름
+pub const CUSTOM: &str = "custom";
+
+/// 이긴 게임 한 판의 기록. 점수 파일에는 한 줄에 하나씩 탭으로 구분해서 저장한다.
+///
+/// `이름\t최솟값\t최댓값\t난이도\t시도 횟수\t걸린 시간(ms)\t시각(유닉스 초)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Score {
+    pub name: String,
+    pub min: u32,
+    pub max: u32,
+    pub difficulty: String,
+    pub attempts: u32,
+    pub duration: Duration,
+    pub timestamp: u64,
+}
+
+impl Score {
+    /// 방금 끝난 게임의 기록을 만든다. 시각은 현재 시스템 시간이다.
+    /// 빈 이름은 parse가 깨진 줄로 보고 버리므로 DEFAULT_NAME으로 바꿔서 남긴다.
+    pub fn new(name: &str, config: &Config, attempts: u32, duration: Duration) -> Score {
+        // 이름에 탭이나 개행이 있으면 파일 형식이 깨지므로 공백으로 바꾼다.
+        let name = name.replace(['\t', '\n', '\r'], " ");
+        Score {
+            name: if name.trim().is_empty() { DEFAULT_NAME.to_string() } else { name },
+            min: config.min,
+            max: config.max,
+            difficulty: Difficulty::of(config).map_or(CUSTOM, |d| d.name()).to_string(),
+            attempts,
+            duration,
+            timestamp: unix_now(),
+        }
+    }
+
+    pub fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.name,
+            self.min,
+            self.max,
+            self.difficulty,
+            self.attempts,
+            self.duration.as_millis(),
+            self.timestamp
+        )
+    }
+
+    /// 점수 파일의 한 줄을 읽는다. 형식이 맞지 않으면 None을 돌려준다.
+    pub fn parse(line: &str) -> Option<Score> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() != 7 || fields[0].is_empty() {
+            return None;
+        }
+        Some(Score {
+            name: fields[0].to_string(),
+            min: fields[1].parse().ok()?,
+            max: fields[2].parse().ok()?,
+            difficulty: fields[3].to_string(),
+            attempts: fields[4].parse().ok()?,
+            duration: Duration::from_millis(fields[5].parse().ok()?),
+            timestamp: fields[6].parse().ok()?,
+        })
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:<12} {:>3}번  {:>6.1}초  {}~{}  {}",
+            self.name,
+            self.attempts,
+            self.duration.as_secs_f64(),
+            self.min,
+            self.max,
+            format_timestamp(self.timestamp)
+        )
+    }
+}
+
+/// 점수 파일에서 읽어온 기록들. 형식이 깨진 줄은 건너뛰고 그 개수만 센다.
+#[derive(Debug, Default)]
+pub struct ScoreBoard {
+    pub scores: Vec<Score>,
+    pub skipped: usize,
+}
+
+impl ScoreBoard {
+    /// 점수 파일을 읽는다.
+    /// 파일이 없으면 error 예제에서처럼 ErrorKind::NotFound를 골라내서 빈 파일을 만들고 빈 점수판을 돌려준다.
+    /// UTF-8이 아닌 내용이 섞여 있어도 전체를 버리지 않도록 손실 변환을 해서 줄 단위로 읽는다.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<ScoreBoard> {
+        let path = path.as_ref();
+        let mut file = match File::open(path) {
+            Ok(file) => file,
+            Err(ref error) if error.kind() == ErrorKind::NotFound => {
+                File::create(path)?;
+                return Ok(ScoreBoard::default());
+            }
+            Err(error) => return Err(error),
+        };
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+
+        let mut board = ScoreBoard::default();
+        for line in String::from_utf8_lossy(&bytes).lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match Score::parse(line) {
+                Some(score) => board.scores.push(score),
+                None => board.skipped += 1,
+            }
+        }
+        Ok(board)
+    }
+
+    /// 점수 파일 끝에 기록 하나를 덧붙인다. 파일이 없으면 만든다.
+    pub fn append<P: AsRef<Path>>(path: P, score: &Score) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", score.to_line())
+    }
+
+    /// 난이도별로 시도 횟수가 적은 순(같으면 빨리 끝낸 순) 상위 n개를 고른다.
+    /// 난이도는 easy, normal, hard, custom 순서이고 기록이 없는 난이도는 빠진다.
+    pub fn top(&self, n: usize) -> Vec<(&str, Vec<&Score>)> {
+        let labels = [
+            Difficulty::Easy.name(),
+            Difficulty::Normal.name(),
+            Difficulty::Hard.name(),
+            CUSTOM,
+        ];
+        let mut table = Vec::new();
+        for label in labels.iter() {
+            let mut scores: Vec<&Score> = self.scores.iter().filter(|s| s.difficulty == *label).collect();
+            if scores.is_empty() {
+                continue;
+            }
+            scores.sort_by_key(|s| (s.attempts, s.duration));
+            scores.truncate(n);
+            table.push((*label, scores));
+        }
+        table
+    }
+
+    /// 난이도별 상위 n개 기록을 표로 출력한다.
+    pub fn print_top<W: Write>(&self, n: usize, mut output: W) -> io::Result<()> {
+        if self.skipped > 0 {
+            writeln!(output, "(점수 파일에서 읽을 수 없는 줄 {}개를 건너뛰었습니다.)", self.skipped)?;
+        }
+        let table = self.top(n);
+        if table.is_empty() {
+            writeln!(output, "아직 기록이 없습니다.")?;
+        }
+        for (label, scores) in table {
+            writeln!(output, "== {} ==", label)?;
+            for (rank, score) in scores.iter().enumerate() {
+                writeln!(output, "{:>2}. {}", rank + 1, score)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 현재 시스템 시간 (유닉스 초). 시계가 1970년보다 이전이면 0 이다.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+/// 유닉스 초를 `YYYY-MM-DD HH:MM:SS` (UTC) 형태로 바꾼다.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+    // 1970-01-01부터 지난 날 수를 그레고리력 날짜로 바꾸는 계산 (Howard Hinnant의 civil_from_days)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(name: &str, difficulty: &str, attempts: u32, millis: u64) -> Score {
+        Score {
+            name: name.to_string(),
+            min: 1,
+            max: 100,
+            difficulty: difficulty.to_string(),
+            attempts,
+            duration: Duration::from_millis(millis),
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn line_round_trip() {
+        let score = score("철수", "normal", 5, 12_345);
+        assert_eq!(Score::parse(&score.to_line()), Some(score));
+
+        let unnamed = Score::new("", &Config::default(), 3, Duration::from_millis(10));
+        assert_eq!(unnamed.name, DEFAULT_NAME);
+        assert_eq!(Score::parse(&unnamed.to_line()), Some(unnamed));
+        assert_eq!(Score::new(" \t", &Config::default(), 3, Duration::from_millis(10)).name, DEFAULT_NAME);
+        assert_eq!(Score::parse("철수\t1\t100"), None);
+        assert_eq!(Score::parse("철수\t1\t100\tnormal\t다섯\t0\t0"), None);
+    }
+
+    #[test]
+    fn top_is_grouped_and_sorted() {
+        let board = ScoreBoard {
+            scores: vec![
+                score("a", "hard", 9, 100),
+                score("b", "easy", 4, 500),
+                score("c", "easy", 4, 300),
+                score("d", "easy", 2, 900),
+            ],
+            skipped: 0,
+        };
+        let top = board.top(2);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].0, "easy");
+        let names: Vec<&str> = top[0].1.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["d", "c"]);
+        assert_eq!(top[1].0, "hard");
+    }
+
+    #[test]
+    fn timestamp_format() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00:00");
+        assert_eq!(format_timestamp(1_700_000_000), "2023-11-14 22:13:20");
+    }
+}
@@ -0,0 +1,134 @@
+/*
+스크립트(배치) 모드. 사람이 아니라 파일이나 파이프에서 추측을 한 줄씩 받아 한 판을 한다.
+
+셸 스크립트에서 --seed와 함께 써서 게임을 테스트용 고정 장치처럼 쓰기 위한 것이라
+"숫자를 입력하세여" 같은 안내는 출력하지 않고, 추측마다 나오는 대답만 출력한다.
+사람이 다시 입력해줄 수 없으므로 추측으로 받아들일 수 없는 줄(UTF-8이 아닌 줄 포함)을 만나면 그 자리에서 멈춘다.
+표준 입력이 터미널이 아니라고 저절로 스크립트 모드가 되지는 않는다. 파이프에서 읽으려면 --script -를 준다.
+어떻게 끝났는지는 종료 코드로 알린다. 2는 잘못된 명령줄 인수에 이미 쓰고 있으므로 건너뛴다.
+빈 줄과 #으로 시작하는 줄은 주석으로 보고 건너뛴다.
+*/
+use std::io::{self, BufRead, Write};
+
+use crate::game::{Game, Outcome, Step};
+
+/// 비밀 숫자를 맞췄다.
+pub const EXIT_WON: i32 = 0;
+/// 기회를 모두 썼거나 제한 시간을 넘겼다.
+pub const EXIT_LOST: i32 = 1;
+/// 추측으로 받아들일 수 없는 줄이 있었다.
+pub const EXIT_INVALID: i32 = 3;
+/// 맞추기 전에 스크립트가 끝났다.
+pub const EXIT_UNFINISHED: i32 = 4;
+
+/// 스크립트로 한 판을 한 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptResult {
+    /// 게임이 끝났다.
+    Finished(Outcome),
+    /// line번째 줄(1부터 셈)을 추측으로 받아들일 수 없어서 멈췄다.
+    Invalid { line: usize },
+}
+
+impl ScriptResult {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ScriptResult::Finished(Outcome::Won(_)) => EXIT_WON,
+            ScriptResult::Finished(Outcome::Lost) | ScriptResult::Finished(Outcome::TimedOut) => EXIT_LOST,
+            ScriptResult::Finished(Outcome::Quit) => EXIT_UNFINISHED,
+            ScriptResult::Invalid { .. } => EXIT_INVALID,
+        }
+    }
+}
+
+/// input의 줄들을 차례로 추측으로 넣어 게임을 한 판 한다.
+/// 안내(intro)와 입력 안내는 출력하지 않고 Game::respond의 대답만 output에 쓴다.
+pub fn run<R: BufRead, W: Write>(game: &mut Game, input: R, mut output: W) -> io::Result<ScriptResult> {
+    game.start(io::sink())?;
+    for (index, line) in input.lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(ref error) if error.kind() == io::ErrorKind::InvalidData => {
+                writeln!(output, "{}번째 줄이 UTF-8 문장이 아니라서 멈춥니다.", index + 1)?;
+                return stop(game, output, index + 1);
+            }
+            Err(error) => return Err(error),
+        };
+        let text = line.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        match game.respond(&line, &mut output)? {
+            Step::Next => (),
+            Step::Over(outcome) => {
+                output.flush()?;
+                return Ok(ScriptResult::Finished(outcome));
+            }
+            Step::Retry => {
+                writeln!(output, "{}번째 줄 '{}'에서 멈춥니다.", index + 1, text)?;
+                return stop(game, output, index + 1);
+            }
+        }
+    }
+    let outcome = game.end_of_input(&mut output)?;
+    output.flush()?;
+    Ok(ScriptResult::Finished(outcome))
+}
+
+/// line번째 줄을 받아들일 수 없어서 게임을 멈춘다.
+fn stop<W: Write>(game: &mut Game, mut output: W, line: usize) -> io::Result<ScriptResult> {
+    output.flush()?;
+    // 이벤트 기록에는 끝내지 못한 게임(quit)으로 남긴다.
+    game.end_of_input(io::sink())?;
+    Ok(ScriptResult::Invalid { line })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn run_script(game: &mut Game, script: &str) -> (ScriptResult, String) {
+        let mut output = Vec::new();
+        let result = run(game, script.as_bytes(), &mut output).unwrap();
+        (result, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn won_without_prompts() {
+        let (result, output) = run_script(&mut Game::new(42), "# 이분 탐색\n50\n\n25\n42\n");
+        assert_eq!(result, ScriptResult::Finished(Outcome::Won(3)));
+        assert_eq!(result.exit_code(), EXIT_WON);
+        assert!(!output.contains("숫자를 입력하세여"));
+        assert!(!output.contains("사이의 숫자를 맞춰보세요"));
+        assert!(output.ends_with("님이 입력한 숫자는 42 입니다.\n님이 맞춤\n"));
+    }
+
+    #[test]
+    fn stops_at_invalid_line() {
+        let (result, output) = run_script(&mut Game::new(42), "50\nabc\n42\n");
+        assert_eq!(result, ScriptResult::Invalid { line: 2 });
+        assert_eq!(result.exit_code(), EXIT_INVALID);
+        assert!(output.ends_with("'abc'은(는) 숫자가 아닙니다.\n2번째 줄 'abc'에서 멈춥니다.\n"));
+    }
+
+    #[test]
+    fn stops_at_non_utf8_line() {
+        let mut output = Vec::new();
+        let result = run(&mut Game::new(42), &b"50\n\xff\xfe\n42\n"[..], &mut output).unwrap();
+        assert_eq!(result, ScriptResult::Invalid { line: 2 });
+        assert_eq!(result.exit_code(), EXIT_INVALID);
+        assert!(String::from_utf8(output).unwrap().ends_with("2번째 줄이 UTF-8 문장이 아니라서 멈춥니다.\n"));
+    }
+
+    #[test]
+    fn lost_and_unfinished() {
+        let config = Config { max_attempts: Some(2), ..Config::default() };
+        let (result, _) = run_script(&mut Game::with_config(42, config), "1\n2\n3\n");
+        assert_eq!(result.exit_code(), EXIT_LOST);
+        let (result, output) = run_script(&mut Game::new(42), "1\n");
+        assert_eq!(result, ScriptResult::Finished(Outcome::Quit));
+        assert_eq!(result.exit_code(), EXIT_UNFINISHED);
+        assert!(output.ends_with("입력이 끝나서 게임을 마칩니다.\n"));
+    }
+}
@@ -0,0 +1,232 @@
+/*
+TCP로 숫자 맞추기 게임을 제공하는 서버.
+
+클라이언트가 접속하면 클라이언트마다 비밀 숫자를 따로 뽑고 스레드를 하나씩 띄워서 게임을 진행한다.
+프로토콜은 한 줄 단위의 텍스트이다.
+
+    서버 -> READY <최솟값> <최댓값> <최대 시도 횟수 또는 ->
+    클라이언트 -> GUESS 42
+    서버 -> LOW | HIGH | WIN <시도 횟수> | LOSE <비밀 숫자> | ERR <이유>
+    클라이언트 -> QUIT
+    서버 -> BYE
+
+LOW는 추측이 비밀 숫자보다 작다는 뜻이다. WIN, LOSE, BYE를 보낸 뒤에는 연결을 끊는다.
+ERR는 시도 횟수에 들어가지 않는다.
+*/
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use rand::XorShiftRng;
+
+use crate::config::Config;
+use crate::game::{seeded_rng, Game};
+use crate::input::parse_guess;
+
+/// 주소를 지정하지 않았을 때 서버가 열고 클라이언트가 접속하는 주소
+pub const DEFAULT_ADDR: &str = "127.0.0.1:7878";
+
+/// 서버가 보내는 응답 한 줄
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    Ready { min: u32, max: u32, max_attempts: Option<u32> },
+    Low,
+    High,
+    Win(u32),
+    Lose(u32),
+    Error(String),
+    Bye,
+}
+
+impl Reply {
+    /// 이 응답을 보낸 뒤 연결을 끊는지
+    pub fn is_final(&self) -> bool {
+        matches!(self, Reply::Win(_) | Reply::Lose(_) | Reply::Bye)
+    }
+
+    /// 응답 한 줄을 읽는다. 클라이언트에서 사용한다.
+    pub fn parse(line: &str) -> Option<Reply> {
+        let line = line.trim();
+        let (command, rest) = match line.find(' ') {
+            Some(space) => (&line[..space], line[space + 1..].trim()),
+            None => (line, ""),
+        };
+        match command {
+            "READY" => {
+                let fields: Vec<&str> = rest.split_whitespace().collect();
+                if fields.len() != 3 {
+                    return None;
+                }
+                let max_attempts = match fields[2] {
+                    "-" => None,
+                    n => Some(n.parse().ok()?),
+                };
+                Some(Reply::Ready { min: fields[0].parse().ok()?, max: fields[1].parse().ok()?, max_attempts })
+            }
+            "LOW" => Some(Reply::Low),
+            "HIGH" => Some(Reply::High),
+            "WIN" => Some(Reply::Win(rest.parse().ok()?)),
+            "LOSE" => Some(Reply::Lose(rest.parse().ok()?)),
+            "ERR" => Some(Reply::Error(rest.to_string())),
+            "BYE" => Some(Reply::Bye),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reply::Ready { min, max, max_attempts: Some(n) } => write!(f, "READY {} {} {}", min, max, n),
+            Reply::Ready { min, max, max_attempts: None } => write!(f, "READY {} {} -", min, max),
+            Reply::Low => write!(f, "LOW"),
+            Reply::High => write!(f, "HIGH"),
+            Reply::Win(attempts) => write!(f, "WIN {}", attempts),
+            Reply::Lose(secret) => write!(f, "LOSE {}", secret),
+            Reply::Error(reason) => write!(f, "ERR {}", reason),
+            Reply::Bye => write!(f, "BYE"),
+        }
+    }
+}
+
+/// 클라이언트가 보낸 한 줄을 처리하고 응답을 돌려준다.
+pub fn handle_line(game: &mut Game, line: &str) -> Reply {
+    let line = line.trim();
+    let (command, argument) = match line.find(' ') {
+        Some(space) => (&line[..space], &line[space + 1..]),
+        None => (line, ""),
+    };
+    match command.to_uppercase().as_str() {
+        "GUESS" => {
+            let guess = match parse_guess(argument, game.config().min, game.config().max) {
+                Ok(guess) => guess,
+                Err(error) => return Reply::Error(error.to_string()),
+            };
+            match game.guess(guess) {
+                Ordering::Equal => Reply::Win(game.attempts()),
+                _ if game.is_lost() => Reply::Lose(game.secret()),
+                Ordering::Less => Reply::Low,
+                Ordering::Greater => Reply::High,
+            }
+        }
+        "QUIT" => Reply::Bye,
+        _ => Reply::Error(format!("알 수 없는 명령입니다: {}", command)),
+    }
+}
+
+/// 한 클라이언트와 게임 한 판을 진행한다. 소켓뿐 아니라 아무 BufRead/Write로도 돌릴 수 있다.
+pub fn serve_client<R: BufRead, W: Write>(game: &mut Game, mut input: R, mut output: W) -> io::Result<()> {
+    let config = game.config();
+    writeln!(output, "{}", Reply::Ready { min: config.min, max: config.max, max_attempts: config.max_attempts })?;
+    output.flush()?;
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        // UTF-8이 아닌 줄에서 연결을 끊지 않고 잘못된 명령으로 대답하도록 바이트로 읽는다.
+        if input.read_until(b'\n', &mut line)? == 0 {
+            return Ok(());
+        }
+        let reply = handle_line(game, &String::from_utf8_lossy(&line));
+        writeln!(output, "{}", reply)?;
+        output.flush()?;
+        if reply.is_final() {
+            return Ok(());
+        }
+    }
+}
+
+/// 접속을 받아 클라이언트마다 스레드를 띄우는 서버
+pub struct Server {
+    listener: TcpListener,
+    config: Config,
+    rng: Arc<Mutex<XorShiftRng>>,
+}
+
+impl Server {
+    /// 주소에 바인드한다. 포트를 0으로 주면 운영체제가 빈 포트를 골라준다. (테스트용)
+    /// 클라이언트들의 비밀 숫자는 seed로 만든 난수 생성기에서 접속 순서대로 뽑는다.
+    pub fn bind<A: ToSocketAddrs>(addr: A, config: Config, seed: u32) -> io::Result<Server> {
+        Ok(Server {
+            listener: TcpListener::bind(addr)?,
+            config,
+            rng: Arc::new(Mutex::new(seeded_rng(seed))),
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// 접속을 계속 받는다. 한 클라이언트의 입출력 에러는 그 클라이언트만 끊고 서버는 계속 돈다.
+    pub fn run(&self) -> io::Result<()> {
+        for stream in self.listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(error) => {
+                    eprintln!("접속을 받지 못했습니다 ({})", error);
+                    continue;
+                }
+            };
+            let game = {
+                let mut rng = self.rng.lock().expect("난수 생성기 잠금 실패");
+                Game::with_rng(&mut *rng, self.config.clone())
+            };
+            thread::spawn(move || {
+                if let Err(error) = handle_client(game, stream) {
+                    eprintln!("클라이언트 처리 중 에러 ({})", error);
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+fn handle_client(mut game: Game, stream: TcpStream) -> io::Result<()> {
+    let reader = BufReader::new(stream.try_clone()?);
+    serve_client(&mut game, reader, stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replies_round_trip() {
+        let replies = [
+            Reply::Ready { min: 1, max: 100, max_attempts: Some(7) },
+            Reply::Ready { min: 1, max: 100, max_attempts: None },
+            Reply::Low,
+            Reply::High,
+            Reply::Win(3),
+            Reply::Lose(42),
+            Reply::Error("'x'은(는) 숫자가 아닙니다.".to_string()),
+            Reply::Bye,
+        ];
+        for reply in replies.iter() {
+            assert_eq!(Reply::parse(&reply.to_string()).as_ref(), Some(reply));
+        }
+        assert_eq!(Reply::parse("WHAT"), None);
+    }
+
+    #[test]
+    fn protocol_in_memory() {
+        let config = Config { max_attempts: Some(5), ..Config::default() };
+        let mut game = Game::with_config(42, config);
+        let mut output = Vec::new();
+        serve_client(&mut game, &b"GUESS 50\nguess 10\nGUESS x\nHELLO\nGUESS 42\nGUESS 1\n"[..], &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "READY 1 100 5\nHIGH\nLOW\nERR 'x'은(는) 숫자가 아닙니다.\nERR 알 수 없는 명령입니다: HELLO\nWIN 3\n"
+        );
+    }
+
+    #[test]
+    fn lose_after_last_attempt() {
+        let config = Config { max_attempts: Some(1), ..Config::default() };
+        let mut game = Game::with_config(42, config);
+        assert_eq!(handle_line(&mut game, "GUESS 41"), Reply::Lose(42));
+    }
+}
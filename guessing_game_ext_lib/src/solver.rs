@@ -0,0 +1,92 @@
+use std::cmp::Ordering;
+use std::io::{self, Write};
+
+use crate::game::{Game, Outcome};
+use crate::strategy::{self, Bisection};
+
+/// 자동 풀이가 던진 추측 하나와 그에 대한 게임의 대답
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Probe {
+    pub guess: u32,
+    pub ordering: Ordering,
+}
+
+/// 후보 구간 [low, high]의 가운데 값. low + high가 넘치지 않도록 차이의 절반을 더한다.
+pub fn midpoint(low: u32, high: u32) -> u32 {
+    low + (high - low) / 2
+}
+
+/// min ~ max 범위(양 끝 포함)에서 이분 탐색이 최악의 경우 필요한 추측 횟수.
+///
+/// 후보가 n개일 때 가운데 값을 물어보면 남는 후보는 많아야 n / 2개이므로
+/// 필요한 횟수는 ceil(log2(n + 1))이다. (n = 100이면 7번)
+/// 후보가 하나 남아도 그 숫자를 직접 불러야 맞춘 것이 되므로 ceil(log2(n))보다 하나 많을 수 있다.
+pub fn worst_case(min: u32, max: u32) -> u32 {
+    let candidates = u64::from(max - min) + 1;
+    64 - candidates.leading_zeros()
+}
+
+/// 게임의 Ordering 대답만 보고 이분 탐색으로 비밀 숫자를 찾는다.
+/// 맞추거나 기회를 다 쓸 때까지 던진 추측들을 순서대로 돌려준다.
+pub fn solve(game: &mut Game) -> Vec<Probe> {
+    strategy::run(&mut Bisection, game)
+}
+
+/// 컴퓨터가 게임을 풀면서 매 추측을 출력한다.
+pub fn play<W: Write>(game: &mut Game, mut output: W) -> io::Result<Outcome> {
+    let (min, max) = (game.config().min, game.config().max);
+    writeln!(output, "컴퓨터가 {} 부터 {} 사이의 숫자를 맞춰봅니다.", min, max)?;
+    for (i, probe) in solve(game).iter().enumerate() {
+        let reply = match probe.ordering {
+            Ordering::Less => "너무 작음",
+            Ordering::Greater => "너무 큼",
+            Ordering::Equal => "맞춤",
+        };
+        writeln!(output, "{:>3}번째 추측: {} -> {}", i + 1, probe.guess, reply)?;
+    }
+    let bound = worst_case(min, max);
+    if game.is_won() {
+        writeln!(output, "{}번 만에 맞췄습니다. (이분 탐색은 최악의 경우에도 {}번 안에 맞춥니다.)", game.attempts(), bound)?;
+        Ok(Outcome::Won(game.attempts()))
+    } else {
+        writeln!(output, "기회가 모자라 맞추지 못했습니다. 정답은 {} 였습니다. (최악의 경우 {}번 필요)", game.secret(), bound)?;
+        Ok(Outcome::Lost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn worst_case_bound() {
+        assert_eq!(worst_case(1, 1), 1);
+        assert_eq!(worst_case(1, 2), 2);
+        assert_eq!(worst_case(1, 100), 7);
+        assert_eq!(worst_case(1, 1000), 10);
+        assert_eq!(worst_case(0, u32::MAX), 33);
+    }
+
+    #[test]
+    fn every_secret_is_found_within_bound() {
+        for &(min, max) in &[(1, 100), (1, 50), (1, 1000), (7, 8), (0, 127)] {
+            let bound = worst_case(min, max);
+            for secret in min..=max {
+                let config = Config { min, max, ..Config::default() };
+                let mut game = Game::with_config(secret, config);
+                let probes = solve(&mut game);
+                assert!(game.is_won());
+                assert!(probes.len() as u32 <= bound, "{} ~ {} 에서 {}을(를) {}번 만에 찾음", min, max, secret, probes.len());
+            }
+        }
+    }
+
+    #[test]
+    fn solver_respects_attempt_limit() {
+        let config = Config { max_attempts: Some(2), ..Config::default() };
+        let mut game = Game::with_config(1, config);
+        assert_eq!(solve(&mut game).len(), 2);
+        assert!(game.is_lost());
+    }
+}
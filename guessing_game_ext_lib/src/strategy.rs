@@ -0,0 +1,163 @@
+use rand::Rng;
+
+use crate::game::Game;
+use crate::solver::{midpoint, Probe};
+
+/// 추측 전략. 비밀 숫자가 남아 있을 수 있는 구간 [low, high]와 지금까지의 추측과 대답(history)을 보고
+/// 다음에 부를 숫자를 정한다. 구간은 run이 게임에서 받아서 넘겨주므로 전략이 history를 다시 훑을 필요는 없다.
+///
+/// 기본 제공 전략들은 항상 남은 구간 안의 숫자를 고르므로 매 추측마다 구간이 줄어들고
+/// 언젠가는 반드시 맞추게 된다.
+pub trait Strategy {
+    fn name(&self) -> &str;
+
+    fn next_guess(&mut self, low: u32, high: u32, history: &[Probe]) -> u32;
+}
+
+/// 전략으로 게임을 맞추거나 기회를 다 쓸 때까지 진행하고 던진 추측들을 돌려준다.
+/// 구간 밖을 부르는 전략도 끝나도록 범위의 숫자 개수(max - min + 1)만큼 추측하면 멈춘다.
+pub fn run<S: Strategy + ?Sized>(strategy: &mut S, game: &mut Game) -> Vec<Probe> {
+    let (min, max) = (game.config().min, game.config().max);
+    let limit = u64::from(max - min) + 1;
+    let mut history = Vec::new();
+    while !game.is_won() && !game.is_lost() && (history.len() as u64) < limit {
+        let (low, high) = game.candidates();
+        let guess = strategy.next_guess(low, high, &history);
+        let ordering = game.guess(guess);
+        history.push(Probe { guess, ordering });
+    }
+    history
+}
+
+/// 남은 구간의 가운데를 부른다. (이분 탐색)
+pub struct Bisection;
+
+impl Strategy for Bisection {
+    fn name(&self) -> &str {
+        "bisection"
+    }
+
+    fn next_guess(&mut self, low: u32, high: u32, _: &[Probe]) -> u32 {
+        midpoint(low, high.max(low))
+    }
+}
+
+/// 남은 구간 안에서 아무 숫자나 부른다.
+pub struct RandomInterval<R: Rng> {
+    rng: R,
+}
+
+impl<R: Rng> RandomInterval<R> {
+    pub fn new(rng: R) -> RandomInterval<R> {
+        RandomInterval { rng }
+    }
+}
+
+impl<R: Rng> Strategy for RandomInterval<R> {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn next_guess(&mut self, low: u32, high: u32, _: &[Probe]) -> u32 {
+        self.rng.gen_range(u64::from(low), u64::from(high.max(low)) + 1) as u32
+    }
+}
+
+/// 남은 구간의 가장 작은 숫자부터 하나씩 부른다.
+pub struct LinearScan;
+
+impl Strategy for LinearScan {
+    fn name(&self) -> &str {
+        "linear"
+    }
+
+    fn next_guess(&mut self, low: u32, _: u32, _: &[Probe]) -> u32 {
+        low
+    }
+}
+
+/// 남은 구간을 황금비(약 0.382 : 0.618)로 나누는 지점을 부른다.
+/// 이분 탐색보다 한쪽으로 치우쳐 있어서 평균 횟수가 조금 더 많다.
+pub struct GoldenSection;
+
+impl Strategy for GoldenSection {
+    fn name(&self) -> &str {
+        "golden"
+    }
+
+    fn next_guess(&mut self, low: u32, high: u32, _: &[Probe]) -> u32 {
+        const RATIO: f64 = 0.381_966_011_250_105_1; // 1 - 1 / 황금비
+        // 구간이 비어 있으면(low > high) 빼기가 넘치지 않도록 low를 부른다.
+        low + (f64::from(high.saturating_sub(low)) * RATIO).round() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cmp::Ordering;
+    use crate::config::Config;
+    use rand::{SeedableRng, XorShiftRng};
+
+    /// 받은 구간을 기억해 두고 항상 범위 밖(0)을 부르는 전략
+    struct Stubborn {
+        intervals: Vec<(u32, u32)>,
+    }
+
+    impl Strategy for Stubborn {
+        fn name(&self) -> &str {
+            "stubborn"
+        }
+
+        fn next_guess(&mut self, low: u32, high: u32, _: &[Probe]) -> u32 {
+            self.intervals.push((low, high));
+            0
+        }
+    }
+
+    #[test]
+    fn run_passes_the_interval_and_stops() {
+        let mut game = Game::with_config(42, Config { min: 1, max: 10, max_attempts: None, ..Config::default() });
+        let mut stubborn = Stubborn { intervals: Vec::new() };
+        assert_eq!(run(&mut stubborn, &mut game).len(), 10);
+        assert!(!game.is_won());
+        assert_eq!(stubborn.intervals[0], (1, 10));
+
+        let mut game = Game::with_config(30, Config::default());
+        let probes = run(&mut Bisection, &mut game);
+        assert_eq!(probes[0], Probe { guess: 50, ordering: Ordering::Greater });
+        assert_eq!(probes[1], Probe { guess: 25, ordering: Ordering::Less });
+    }
+
+    #[test]
+    fn empty_interval_does_not_panic() {
+        assert_eq!(GoldenSection.next_guess(10, 9, &[]), 10);
+        assert_eq!(Bisection.next_guess(10, 9, &[]), 10);
+        assert_eq!(RandomInterval::new(XorShiftRng::from_seed([1, 2, 3, 4])).next_guess(10, 9, &[]), 10);
+    }
+
+    #[test]
+    fn every_builtin_strategy_wins() {
+        let mut strategies: Vec<Box<dyn Strategy>> = vec![
+            Box::new(Bisection),
+            Box::new(RandomInterval::new(XorShiftRng::from_seed([1, 2, 3, 4]))),
+            Box::new(LinearScan),
+            Box::new(GoldenSection),
+        ];
+        for strategy in strategies.iter_mut() {
+            for secret in 1..=100 {
+                let mut game = Game::with_config(secret, Config::default());
+                let probes = run(strategy.as_mut(), &mut game);
+                assert!(game.is_won(), "{} 전략이 {}을(를) 못 맞춤", strategy.name(), secret);
+                assert!(probes.len() <= 100);
+            }
+        }
+    }
+
+    #[test]
+    fn linear_scan_counts_up() {
+        let mut game = Game::with_config(4, Config::default());
+        let guesses: Vec<u32> = run(&mut LinearScan, &mut game).iter().map(|p| p.guess).collect();
+        assert_eq!(guesses, vec![1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,29 @@
+use std::io::{self, IsTerminal};
+use std::process::{Command, Stdio};
+
+/// 살아 있는 동안 터미널 에코를 꺼두고, 사라질 때(drop) 다시 켠다.
+/// 에코가 꺼진 동안 입력한 엔터는 화면에 찍히지 않으므로 필요하면 부르는 쪽이 자기 출력에 줄을 바꿔준다.
+pub struct EchoGuard(());
+
+impl Drop for EchoGuard {
+    fn drop(&mut self) {
+        let _ = stty("echo");
+    }
+}
+
+fn stty(arg: &str) -> io::Result<bool> {
+    let status = Command::new("stty").arg(arg).stdin(Stdio::inherit()).status()?;
+    Ok(status.success())
+}
+
+/// 표준 입력이 터미널이면 입력한 글자가 화면에 보이지 않게 한다. (비밀 숫자 입력용)
+/// 파이프로 입력을 받거나 stty를 쓸 수 없는 환경이면 아무것도 하지 않고 None을 돌려준다.
+pub fn hide_input() -> Option<EchoGuard> {
+    if !io::stdin().is_terminal() {
+        return None;
+    }
+    match stty("-echo") {
+        Ok(true) => Some(EchoGuard(())),
+        _ => None,
+    }
+}
@@ -0,0 +1,141 @@
+use std::io::{self, Write};
+
+use rand::Rng;
+
+use crate::config::Config;
+use crate::game::{seeded_rng, Game};
+use crate::solver::worst_case;
+use crate::strategy::{self, Bisection, GoldenSection, LinearScan, RandomInterval, Strategy};
+
+/// 시드를 지정하지 않았을 때 쓰는 값. 같은 시드면 항상 같은 비밀 숫자들로 겨룬다.
+pub const DEFAULT_SEED: u32 = 2018;
+
+/// 한 판에 쓸 수 있는 추측 수는 이분 탐색이 최악의 경우 필요한 횟수의 이 배수이다.
+pub const BUDGET_FACTOR: u32 = 4;
+
+/// min ~ max 범위에서 한 판에 쓸 수 있는 추측 수.
+/// 범위가 넓어도 linear 같은 느린 전략이 수십억 번 추측하지 않도록 범위의 크기가 아니라 log2에 비례하게 정한다.
+pub fn budget(min: u32, max: u32) -> u32 {
+    BUDGET_FACTOR * worst_case(min, max)
+}
+
+/// 전략 하나의 대회 성적
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    pub name: String,
+    pub games: usize,
+    /// 추측 수를 다 쓰고도 맞추지 못한 게임 수
+    pub lost: usize,
+    /// 평균 추측 수. 맞추지 못한 게임은 추측 수를 다 쓴 것으로 센다.
+    pub mean: f64,
+    pub min: u32,
+    pub max: u32,
+}
+
+/// 기본 제공 전략 전부. random 전략의 난수도 시드로부터 만들어서 결과가 재현되게 한다.
+pub fn builtin_strategies(seed: u32) -> Vec<Box<dyn Strategy>> {
+    vec![
+        Box::new(Bisection),
+        Box::new(RandomInterval::new(seeded_rng(seed.wrapping_add(1)))),
+        Box::new(LinearScan),
+        Box::new(GoldenSection),
+    ]
+}
+
+/// 시드로 정해진 비밀 숫자 games개를 뽑아 각 전략이 모두 풀게 하고 성적을 매긴다.
+/// 설정의 시도 횟수 대신 모든 전략에 같은 추측 수(budget)를 주고, 그 안에 맞추지 못하면 진 것으로 센다.
+pub fn run(strategies: &mut [Box<dyn Strategy>], config: &Config, games: usize, seed: u32) -> Vec<Standing> {
+    let config = Config { max_attempts: Some(budget(config.min, config.max)), ..config.clone() };
+    let mut secrets = seeded_rng(seed);
+    let secrets: Vec<u32> = (0..games)
+        .map(|_| secrets.gen_range(u64::from(config.min), u64::from(config.max) + 1) as u32)
+        .collect();
+
+    strategies
+        .iter_mut()
+        .map(|strategy| {
+            let mut lost = 0;
+            let attempts: Vec<u32> = secrets
+                .iter()
+                .map(|&secret| {
+                    let mut game = Game::with_config(secret, config.clone());
+                    strategy::run(strategy.as_mut(), &mut game);
+                    if !game.is_won() {
+                        lost += 1;
+                    }
+                    game.attempts()
+                })
+                .collect();
+            let total: u64 = attempts.iter().map(|&a| u64::from(a)).sum();
+            Standing {
+                name: strategy.name().to_string(),
+                games,
+                lost,
+                mean: if games == 0 { 0.0 } else { total as f64 / games as f64 },
+                min: attempts.iter().cloned().min().unwrap_or(0),
+                max: attempts.iter().cloned().max().unwrap_or(0),
+            }
+        })
+        .collect()
+}
+
+/// 성적표를 맞추지 못한 게임이 적은 순서로, 같으면 평균 시도 횟수가 적은 순서로 출력한다.
+pub fn print_table<W: Write>(standings: &[Standing], mut output: W) -> io::Result<()> {
+    let mut standings: Vec<&Standing> = standings.iter().collect();
+    standings.sort_by(|a, b| {
+        a.lost.cmp(&b.lost).then(a.mean.partial_cmp(&b.mean).unwrap_or(std::cmp::Ordering::Equal))
+    });
+    writeln!(output, "{:<4} {:<12} {:>8} {:>6} {:>8} {:>6} {:>6}", "순위", "전략", "게임 수", "실패", "평균", "최소", "최대")?;
+    for (rank, standing) in standings.iter().enumerate() {
+        writeln!(
+            output,
+            "{:<6} {:<14} {:>10} {:>8} {:>10.3} {:>8} {:>8}",
+            rank + 1,
+            standing.name,
+            standing.games,
+            standing.lost,
+            standing.mean,
+            standing.min,
+            standing.max
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bisection_beats_linear_scan() {
+        let mut strategies = builtin_strategies(DEFAULT_SEED);
+        let standings = run(&mut strategies, &Config::default(), 1000, DEFAULT_SEED);
+        let find = |name: &str| standings.iter().find(|s| s.name == name).unwrap().clone();
+
+        let bisection = find("bisection");
+        assert!(bisection.max <= 7);
+        assert_eq!(bisection.lost, 0);
+        assert!(find("linear").lost > 0);
+        assert!(bisection.mean < find("golden").mean);
+        assert!(find("golden").mean < find("linear").mean);
+    }
+
+    #[test]
+    fn wide_range_stays_within_budget() {
+        let config = Config { min: 1, max: 1_000_000_000, ..Config::default() };
+        let standings = run(&mut builtin_strategies(DEFAULT_SEED), &config, 3, DEFAULT_SEED);
+        assert_eq!(budget(1, 1_000_000_000), 120);
+        for standing in &standings {
+            assert!(standing.max <= 120, "{}: {}", standing.name, standing.max);
+        }
+        let linear = standings.iter().find(|s| s.name == "linear").unwrap();
+        assert_eq!((linear.lost, linear.max), (3, 120));
+    }
+
+    #[test]
+    fn same_seed_same_result() {
+        let a = run(&mut builtin_strategies(7), &Config::default(), 200, 7);
+        let b = run(&mut builtin_strategies(7), &Config::default(), 200, 7);
+        assert_eq!(a, b);
+    }
+}
@@ -0,0 +1,179 @@
+/*
+전체 화면 터미널 UI.
+
+일반 모드는 println!으로 한 줄씩 내려가며 출력하지만, 이 모드는 입력할 때마다 화면을 지우고
+남은 후보 구간 막대, 지난 추측, 남은 기회와 힌트를 같은 자리에 다시 그린다.
+ANSI 이스케이프 코드만 쓰므로 외부 crate는 필요 없다.
+게임 진행은 Game::respond에 맡기고, respond가 출력한 문장들은 화면 아래쪽 메시지 칸에 보여준다.
+입력을 파이프로 받거나 출력을 파일로 보낼 때는 main에서 일반 모드를 쓴다.
+*/
+use std::cmp::Ordering;
+use std::io::{self, BufRead, Write};
+
+use crate::game::{Game, Outcome, Step};
+
+/// 화면을 지우고 커서를 왼쪽 위로 옮긴다.
+const CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// 구간 막대의 칸 수
+pub const BAR_WIDTH: usize = 50;
+
+/// 지난 추측을 최근 몇 개까지 보여줄지
+const HISTORY_LINES: usize = 10;
+
+/// min ~ max 범위를 width칸으로 나누고 low ~ high와 겹치는 칸은 █, 나머지는 ·로 그린다.
+/// 칸 하나에 숫자가 여러 개 들어가도 그중 하나라도 후보면 █ 이므로 후보가 남아 있는 한 막대가 사라지지 않는다.
+pub fn interval_bar(min: u32, max: u32, low: u32, high: u32, width: usize) -> String {
+    let size = u64::from(max - min) + 1;
+    let width = (width as u64).min(size);
+    (0..width)
+        .map(|i| {
+            // 이 칸이 맡은 숫자들 [start, end)
+            let start = u64::from(min) + i * size / width;
+            let end = u64::from(min) + (i + 1) * size / width;
+            if start <= u64::from(high) && end > u64::from(low) {
+                '█'
+            } else {
+                '·'
+            }
+        })
+        .collect()
+}
+
+/// 게임 상태와 메시지로 화면 한 장을 그린다. 화면을 지우는 코드는 넣지 않는다.
+pub fn render(game: &Game, messages: &[String]) -> String {
+    let config = game.config();
+    let (low, high) = game.candidates();
+    let mut screen = String::new();
+    screen.push_str("=== 숫자 맞추기 ===\n\n");
+    screen.push_str(&format!("남은 후보: {} ~ {} ({}개)\n", low, high, u64::from(high - low) + 1));
+    let bar = interval_bar(config.min, config.max, low, high, BAR_WIDTH);
+    let width = bar.chars().count();
+    screen.push_str(&format!("[{}]\n", bar));
+    screen.push_str(&format!(" {:<half$}{:>rest$}\n\n", config.min, config.max, half = width / 2, rest = width - width / 2));
+
+    match game.remaining() {
+        Some(remaining) => screen.push_str(&format!("시도: {}번 (남은 기회 {}번)\n", game.attempts(), remaining)),
+        None => screen.push_str(&format!("시도: {}번\n", game.attempts())),
+    }
+    if let Some(left) = game.guess_time_left() {
+        screen.push_str(&format!("이번 추측 남은 시간: {:.1}초\n", left.as_secs_f64()));
+    }
+    if let Some(left) = game.time_left() {
+        screen.push_str(&format!("전체 남은 시간: {:.1}초\n", left.as_secs_f64()));
+    }
+    if config.hints {
+        let state = if game.hint_used() { "이미 씀" } else { "'힌트'를 입력하면 한 번 쓸 수 있음" };
+        screen.push_str(&format!("힌트: {}\n", state));
+    }
+
+    screen.push_str("\n지난 추측:\n");
+    let guesses = game.guesses();
+    if guesses.is_empty() {
+        screen.push_str("  (아직 없음)\n");
+    }
+    let skip = guesses.len().saturating_sub(HISTORY_LINES);
+    for (i, &(guess, ordering)) in guesses.iter().enumerate().skip(skip) {
+        let reply = match ordering {
+            Ordering::Less => "너무 작음",
+            Ordering::Greater => "너무 큼",
+            Ordering::Equal => "맞춤",
+        };
+        screen.push_str(&format!("  {:>2}. {:>10}  {}\n", i + 1, guess, reply));
+    }
+
+    screen.push('\n');
+    for message in messages {
+        screen.push_str(message);
+        screen.push('\n');
+    }
+    screen
+}
+
+/// 출력된 내용을 메시지 줄들로 나눈다.
+fn lines(buffer: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(buffer).lines().map(String::from).collect()
+}
+
+/// 전체 화면 모드로 게임을 한 판 한다. Game::play와 같은 결과를 돌려준다.
+pub fn play<R: BufRead, W: Write>(game: &mut Game, mut input: R, mut output: W) -> io::Result<Outcome> {
+    let mut buffer = Vec::new();
+    game.start(&mut buffer)?;
+    let mut messages = lines(&buffer);
+    loop {
+        game.begin_turn();
+        write!(output, "{}{}> ", CLEAR, render(game, &messages))?;
+        output.flush()?;
+
+        // UTF-8이 아닌 줄도 에러로 끝내지 않고 잘못된 입력으로 알려주도록 바이트로 읽는다. (Prompt::ask_with와 같음)
+        let mut line = Vec::new();
+        let mut buffer = Vec::new();
+        let outcome = match input.read_until(b'\n', &mut line) {
+            // 입력을 기다리는 동안 제한 시간이 지났다. (clock::TimedInput)
+            Err(ref error) if error.kind() == io::ErrorKind::TimedOut => Some(game.time_up(&mut buffer)?),
+            Err(error) => return Err(error),
+            Ok(0) => Some(game.end_of_input(&mut buffer)?),
+            Ok(_) => match game.respond(&String::from_utf8_lossy(&line), &mut buffer)? {
+                Step::Over(outcome) => Some(outcome),
+                Step::Retry | Step::Next => None,
+            },
+        };
+        messages = lines(&buffer);
+        if let Some(outcome) = outcome {
+            write!(output, "{}{}", CLEAR, render(game, &messages))?;
+            output.flush()?;
+            return Ok(outcome);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn bar_marks_the_candidates() {
+        assert_eq!(interval_bar(1, 10, 1, 10, 10), "██████████");
+        assert_eq!(interval_bar(1, 10, 4, 6, 10), "···███····");
+        // 칸 하나가 숫자 10개를 맡으면 후보가 하나라도 그 칸은 채운다.
+        assert_eq!(interval_bar(1, 100, 42, 42, 10), "····█·····");
+        // 범위가 칸 수보다 작으면 범위 크기만큼만 그린다.
+        assert_eq!(interval_bar(1, 3, 2, 2, 50), "·█·");
+    }
+
+    #[test]
+    fn screen_shows_state() {
+        let config = Config { max_attempts: Some(7), hints: true, ..Config::default() };
+        let mut game = Game::with_config(42, config);
+        game.guess(50);
+        game.guess(25);
+        let screen = render(&game, &["너무 작음".to_string()]);
+        assert!(screen.contains("남은 후보: 26 ~ 49 (24개)\n"));
+        assert!(screen.contains("시도: 2번 (남은 기회 5번)\n"));
+        assert!(screen.contains("힌트: '힌트'를 입력하면 한 번 쓸 수 있음\n"));
+        assert!(screen.contains("   1.         50  너무 큼\n   2.         25  너무 작음\n"));
+        assert!(screen.ends_with("\n너무 작음\n"));
+    }
+
+    #[test]
+    fn play_redraws_after_each_input() {
+        let mut game = Game::new(42);
+        let mut output = Vec::new();
+        let outcome = play(&mut game, &b"50\nabc\n42\n"[..], &mut output).unwrap();
+        assert_eq!(outcome, Outcome::Won(2));
+        let output = String::from_utf8(output).unwrap();
+        // 처음 화면, 입력 세 번마다 한 번씩
+        assert_eq!(output.matches(CLEAR).count(), 4);
+        assert!(output.contains("1 부터 100 사이의 숫자를 맞춰보세요.\n> "));
+        assert!(output.contains("'abc'은(는) 숫자가 아닙니다.\n> "));
+        assert!(output.ends_with("님이 맞춤\n"));
+    }
+
+    #[test]
+    fn play_ends_on_eof() {
+        let mut game = Game::new(42);
+        let outcome = play(&mut game, &b""[..], io::sink()).unwrap();
+        assert_eq!(outcome, Outcome::Quit);
+    }
+}
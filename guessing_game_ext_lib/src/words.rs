@@ -0,0 +1,362 @@
+/*
+단어 맞추기(Wordle 비슷한 게임).
+
+단어 목록에서 숨겨진 단어 하나를 뽑고, 플레이어가 추측한 단어의 글자마다 맞음/있음/없음을 색으로 표시한다.
+collections 예제에서 본 것처럼 한글은 bytes()로 쪼개면 한 글자가 3바이트로 나뉘고,
+chars()로 쪼개도 "감"과 "곰"은 서로 다른 글자일 뿐 ㄱ, ㅁ이 같다는 것을 알 수 없다.
+그래서 한글 음절은 초성, 중성, 종성 자모로 분해해서 자모 단위로 비교한다. (꼬들과 같은 방식)
+ㅘ, ㄳ 같은 겹모음/겹받침은 더 쪼개지 않고 자모 하나로 본다.
+*/
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use prompt::{Prompt, PromptError};
+use rand::Rng;
+
+use crate::config::Config;
+use crate::game::{write_remaining, Outcome, END_OF_INPUT};
+
+/// 단어 목록 파일을 주지 않았을 때 쓰는 단어들
+pub const BUILTIN_WORDS: &[&str] = &[
+    "사과", "바다", "하늘", "나무", "학교", "친구", "사랑", "가족", "음악", "운동", "여름", "겨울", "시간", "사람",
+    "우주", "기차", "연필", "책상", "구름", "노래", "바람", "문제", "생각", "마음", "도시", "시장", "편지", "공원",
+];
+
+/// 기회를 정하지 않았을 때의 최대 시도 횟수
+pub const DEFAULT_ATTEMPTS: u32 = 6;
+
+const SYLLABLE_BASE: u32 = 0xAC00;
+const SYLLABLE_LAST: u32 = 0xD7A3;
+const INITIALS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+const MEDIALS: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ', 'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+/// 종성. 0번은 받침이 없다는 뜻이다.
+const FINALS: [Option<char>; 28] = [
+    None, Some('ㄱ'), Some('ㄲ'), Some('ㄳ'), Some('ㄴ'), Some('ㄵ'), Some('ㄶ'), Some('ㄷ'), Some('ㄹ'), Some('ㄺ'),
+    Some('ㄻ'), Some('ㄼ'), Some('ㄽ'), Some('ㄾ'), Some('ㄿ'), Some('ㅀ'), Some('ㅁ'), Some('ㅂ'), Some('ㅄ'), Some('ㅅ'),
+    Some('ㅆ'), Some('ㅇ'), Some('ㅈ'), Some('ㅊ'), Some('ㅋ'), Some('ㅌ'), Some('ㅍ'), Some('ㅎ'),
+];
+
+/// 단어를 비교 단위로 쪼갠다. 한글 음절은 자모로 분해하고, 영문자는 소문자로 바꾸고, 나머지 글자는 그대로 둔다.
+///
+/// 유니코드의 한글 음절(가 ~ 힣)은 (초성 * 21 + 중성) * 28 + 종성 순서로 배치되어 있으므로 나눗셈으로 분해할 수 있다.
+pub fn decompose(word: &str) -> Vec<char> {
+    let mut letters = Vec::new();
+    for c in word.chars() {
+        let code = c as u32;
+        if (SYLLABLE_BASE..=SYLLABLE_LAST).contains(&code) {
+            let index = (code - SYLLABLE_BASE) as usize;
+            letters.push(INITIALS[index / (21 * 28)]);
+            letters.push(MEDIALS[index / 28 % 21]);
+            letters.extend(FINALS[index % 28]);
+        } else {
+            letters.push(c.to_ascii_lowercase());
+        }
+    }
+    letters
+}
+
+/// 글자 하나에 대한 표시
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    /// 같은 자리에 있다.
+    Correct,
+    /// 단어 안에 있지만 다른 자리에 있다.
+    Present,
+    /// 단어 안에 없다. (또는 이미 다른 자리에서 모두 표시했다.)
+    Absent,
+}
+
+/// 추측의 글자마다 표시를 붙인다. 두 단어는 같은 길이로 분해되어 있어야 한다.
+///
+/// 같은 글자가 여러 번 나오면 정답에 있는 개수만큼만 Correct/Present가 되고 나머지는 Absent 이다.
+/// 그래서 자리가 맞은 글자를 먼저 표시하고 남은 글자로 Present를 표시한다.
+pub fn score(answer: &[char], guess: &[char]) -> Vec<Mark> {
+    let mut marks = vec![Mark::Absent; guess.len()];
+    let mut unmatched: Vec<char> = Vec::new();
+    for (i, (a, g)) in answer.iter().zip(guess).enumerate() {
+        if a == g {
+            marks[i] = Mark::Correct;
+        } else {
+            unmatched.push(*a);
+        }
+    }
+    for (i, g) in guess.iter().enumerate() {
+        if marks[i] == Mark::Correct {
+            continue;
+        }
+        if let Some(position) = unmatched.iter().position(|a| a == g) {
+            unmatched.remove(position);
+            marks[i] = Mark::Present;
+        }
+    }
+    marks
+}
+
+/// 추측한 글자들과 표시를 한 줄로 그린다.
+/// color가 참이면 ANSI 배경색(초록: 맞음, 노랑: 있음, 회색: 없음)을 쓰고,
+/// 파이프처럼 색을 쓸 수 없는 곳에서는 [맞음] (있음) 과 괄호 없는 글자(없음)로 나타낸다.
+pub fn render(letters: &[char], marks: &[Mark], color: bool) -> String {
+    let mut line = String::new();
+    for (letter, mark) in letters.iter().zip(marks) {
+        let cell = match (mark, color) {
+            (Mark::Correct, true) => format!("\x1b[30;42m {} \x1b[0m", letter),
+            (Mark::Present, true) => format!("\x1b[30;43m {} \x1b[0m", letter),
+            (Mark::Absent, true) => format!("\x1b[37;100m {} \x1b[0m", letter),
+            (Mark::Correct, false) => format!("[{}]", letter),
+            (Mark::Present, false) => format!("({})", letter),
+            (Mark::Absent, false) => format!(" {} ", letter),
+        };
+        line.push_str(&cell);
+    }
+    line
+}
+
+/// 단어로 받아들일 수 없는 추측. 시도 횟수에 들어가지 않는다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordError {
+    Empty,
+    /// 한글 음절이나 영문자가 아닌 글자가 있다.
+    InvalidLetter { word: String, letter: char },
+    /// 분해한 글자 수가 정답과 다르다.
+    WrongLength { word: String, letters: usize, expected: usize },
+}
+
+impl fmt::Display for WordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WordError::Empty => write!(f, "아무것도 입력하지 않았습니다. 단어를 입력해주세요."),
+            WordError::InvalidLetter { word, letter } => {
+                write!(f, "'{}'에 쓸 수 없는 글자 '{}'이(가) 있습니다. 완성된 한글이나 영문자만 입력해주세요.", word, letter)
+            }
+            WordError::WrongLength { word, letters, expected } => {
+                write!(f, "'{}'은(는) 자모 {}개입니다. 자모 {}개로 된 단어를 입력해주세요.", word, letters, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WordError {}
+
+/// 단어 목록 파일을 읽는다. 형식은 parse_words를 본다.
+pub fn load_words(path: &Path) -> io::Result<Vec<String>> {
+    parse_words(&fs::read_to_string(path)?)
+}
+
+/// 단어 목록을 읽는다. 한 줄에 단어 하나이고, 빈 줄과 #으로 시작하는 줄은 건너뛴다.
+/// 추측으로 입력할 수 없는 단어(완성된 한글이나 영문자가 아닌 글자가 있는 단어)가 있으면
+/// 몇 번째 줄인지 알려주는 ErrorKind::InvalidData 에러를 돌려준다.
+pub fn parse_words(text: &str) -> io::Result<Vec<String>> {
+    let mut words = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let word = line.trim();
+        if word.is_empty() || word.starts_with('#') {
+            continue;
+        }
+        if let Err(error) = check_letters(word) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("{}번째 줄: {}", index + 1, error)));
+        }
+        words.push(word.to_string());
+    }
+    if words.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "단어 목록이 비어 있습니다"));
+    }
+    Ok(words)
+}
+
+/// 단어가 완성된 한글 음절과 영문자로만 이루어졌는지 확인한다.
+fn check_letters(word: &str) -> Result<(), WordError> {
+    let is_syllable = |c: char| (SYLLABLE_BASE..=SYLLABLE_LAST).contains(&(c as u32));
+    match word.chars().find(|&c| !is_syllable(c) && !c.is_ascii_alphabetic()) {
+        Some(letter) => Err(WordError::InvalidLetter { word: word.to_string(), letter }),
+        None => Ok(()),
+    }
+}
+
+/// 단어 맞추기 한 판의 상태. 설정에서는 최대 시도 횟수와 디버그 여부만 사용한다.
+#[derive(Debug)]
+pub struct WordGame {
+    answer: String,
+    letters: Vec<char>,
+    config: Config,
+    attempts: u32,
+    won: bool,
+    color: bool,
+}
+
+impl WordGame {
+    pub fn new(answer: &str, config: Config) -> WordGame {
+        WordGame {
+            answer: answer.to_string(),
+            letters: decompose(answer),
+            config,
+            attempts: 0,
+            won: false,
+            color: false,
+        }
+    }
+
+    /// 주입받은 난수 생성기로 단어 목록에서 정답을 뽑는다. 목록이 비어 있으면 안 된다.
+    pub fn with_rng<R: Rng, S: AsRef<str>>(rng: &mut R, words: &[S], config: Config) -> WordGame {
+        let answer = &words[rng.gen_range(0, words.len())];
+        WordGame::new(answer.as_ref(), config)
+    }
+
+    /// 결과를 ANSI 색으로 표시할지 정한다. 기본값은 색 없음이다.
+    pub fn color(mut self, color: bool) -> WordGame {
+        self.color = color;
+        self
+    }
+
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn remaining(&self) -> u32 {
+        self.config.max_attempts.unwrap_or(DEFAULT_ATTEMPTS).saturating_sub(self.attempts)
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.won
+    }
+
+    /// 추측을 자모로 분해해서 정답과 비교한다. 잘못된 단어는 시도 횟수에 넣지 않는다.
+    pub fn guess(&mut self, word: &str) -> Result<(Vec<char>, Vec<Mark>), WordError> {
+        let word = word.trim();
+        if word.is_empty() {
+            return Err(WordError::Empty);
+        }
+        check_letters(word)?;
+        let letters = decompose(word);
+        if letters.len() != self.letters.len() {
+            return Err(WordError::WrongLength {
+                word: word.to_string(),
+                letters: letters.len(),
+                expected: self.letters.len(),
+            });
+        }
+        let marks = score(&self.letters, &letters);
+        self.attempts += 1;
+        self.won = marks.iter().all(|&mark| mark == Mark::Correct);
+        Ok((letters, marks))
+    }
+
+    /// Game::play와 같은 입력 루프. 추측마다 자모별 표시를 한 줄로 출력한다.
+    pub fn play<R: BufRead, W: Write>(&mut self, mut input: R, mut output: W) -> io::Result<Outcome> {
+        writeln!(output, "자모 {}개로 된 단어를 맞춰보세요. 기회는 {}번 입니다.", self.letters.len(), self.remaining())?;
+        writeln!(output, "[ㄱ]: 같은 자리에 있음, (ㄱ): 다른 자리에 있음, ㄱ: 없음")?;
+        if self.config.debug {
+            writeln!(output, "[디버그] 정답은 {} 입니다.", self.answer)?;
+        }
+        loop {
+            let (letters, marks) = match Prompt::new(&mut input, &mut output).ask_with("단어를 입력하세요", |line| self.guess(line)) {
+                Ok(result) => result,
+                Err(PromptError::Eof) => {
+                    writeln!(output, "{}", END_OF_INPUT)?;
+                    return Ok(Outcome::Quit);
+                }
+                Err(PromptError::Io(error)) => return Err(error),
+            };
+            writeln!(output, "{}", render(&letters, &marks, self.color))?;
+            if self.won {
+                writeln!(output, "님이 맞춤 ({}번 만에)", self.attempts)?;
+                return Ok(Outcome::Won(self.attempts));
+            }
+            if write_remaining(&mut output, Some(self.remaining()), &self.answer)? {
+                return Ok(Outcome::Lost);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::seeded_rng;
+    use Mark::{Absent, Correct, Present};
+
+    #[test]
+    fn hangul_is_decomposed_into_jamo() {
+        assert_eq!(decompose("한글"), vec!['ㅎ', 'ㅏ', 'ㄴ', 'ㄱ', 'ㅡ', 'ㄹ']);
+        assert_eq!(decompose("가힣"), vec!['ㄱ', 'ㅏ', 'ㅎ', 'ㅣ', 'ㅎ']);
+        assert_eq!(decompose("닭"), vec!['ㄷ', 'ㅏ', 'ㄺ']);
+        assert_eq!(decompose("Apple"), vec!['a', 'p', 'p', 'l', 'e']);
+    }
+
+    #[test]
+    fn jamo_are_compared_not_bytes() {
+        // "감"과 "곰"은 바이트도, 글자도 다르지만 ㄱ과 ㅁ은 같은 자리에 있다.
+        assert_ne!("감".as_bytes()[0..3], "곰".as_bytes()[0..3]);
+        assert_eq!(score(&decompose("감"), &decompose("곰")), vec![Correct, Absent, Correct]);
+        // 음절의 순서가 바뀌어도 자모는 모두 단어 안에 있다.
+        assert_eq!(score(&decompose("사과"), &decompose("과사")), vec![Present, Present, Present, Present]);
+    }
+
+    #[test]
+    fn repeated_letters_are_marked_once_per_occurrence() {
+        let answer: Vec<char> = "abbey".chars().collect();
+        assert_eq!(score(&answer, &"babes".chars().collect::<Vec<_>>()), vec![Present, Present, Correct, Correct, Absent]);
+        assert_eq!(score(&answer, &"kebab".chars().collect::<Vec<_>>()), vec![Absent, Present, Correct, Present, Present]);
+        assert_eq!(score(&answer, &"bbbbb".chars().collect::<Vec<_>>()), vec![Absent, Correct, Correct, Absent, Absent]);
+    }
+
+    #[test]
+    fn render_with_and_without_color() {
+        let letters = decompose("곰");
+        let marks = score(&decompose("감"), &letters);
+        assert_eq!(render(&letters, &marks, false), "[ㄱ] ㅗ [ㅁ]");
+        assert_eq!(render(&letters, &marks, true), "\x1b[30;42m ㄱ \x1b[0m\x1b[37;100m ㅗ \x1b[0m\x1b[30;42m ㅁ \x1b[0m");
+    }
+
+    #[test]
+    fn play_validates_and_marks_guesses() {
+        let mut game = WordGame::new("하늘", Config::default());
+        let mut output = Vec::new();
+        let outcome = game.play("사과\n하늘!\n나물\n하늘\n".as_bytes(), &mut output).unwrap();
+        assert_eq!(outcome, Outcome::Won(2));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("자모 5개로 된 단어를 맞춰보세요. 기회는 6번 입니다."));
+        assert!(output.contains("'사과'은(는) 자모 4개입니다. 자모 5개로 된 단어를 입력해주세요."));
+        assert!(output.contains("'하늘!'에 쓸 수 없는 글자 '!'이(가) 있습니다."));
+        assert!(output.contains("(ㄴ)[ㅏ] ㅁ  ㅜ [ㄹ]\n"));
+        assert!(output.ends_with("[ㅎ][ㅏ][ㄴ][ㅡ][ㄹ]\n님이 맞춤 (2번 만에)\n"));
+    }
+
+    #[test]
+    fn play_ends_when_attempts_run_out() {
+        let config = Config { max_attempts: Some(1), ..Config::default() };
+        let mut game = WordGame::new("하늘", config);
+        let outcome = game.play("나라라\n".as_bytes(), io::sink()).unwrap();
+        assert_eq!(outcome, Outcome::Quit);
+        let outcome = game.play("나물\n".as_bytes(), io::sink()).unwrap();
+        assert_eq!(outcome, Outcome::Lost);
+    }
+
+    #[test]
+    fn answer_comes_from_the_list() {
+        let mut rng = seeded_rng(3);
+        for _ in 0..100 {
+            let game = WordGame::with_rng(&mut rng, BUILTIN_WORDS, Config::default());
+            assert!(BUILTIN_WORDS.contains(&game.answer()));
+        }
+    }
+
+    #[test]
+    fn word_list_is_validated() {
+        assert_eq!(parse_words("# 과일\n  사과 \n\nbanana\n").unwrap(), vec!["사과", "banana"]);
+        let error = parse_words("사과\n# 주석\n배 2\n").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().starts_with("3번째 줄: '배 2'에 쓸 수 없는 글자 ' '이(가) 있습니다."));
+        assert!(parse_words("ㄱㄴ\n").is_err());
+        assert!(parse_words("# 주석뿐\n").is_err());
+    }
+}
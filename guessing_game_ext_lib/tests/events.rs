@@ -0,0 +1,40 @@
+extern crate test_support;
+
+use std::ffi::OsStr;
+use std::fs;
+
+use test_support::{run, seeded_secret_and_miss, TempDir};
+
+#[test]
+fn log_option_writes_json_lines() {
+    let dir = TempDir::new("guessing_game_events");
+    let path = dir.join("events.jsonl");
+    let (secret, wrong) = seeded_secret_and_miss(2018);
+    let (scores, history) = (dir.join("scores.txt"), dir.join("history.txt"));
+
+    let args: [&OsStr; 8] = [
+        "--seed".as_ref(),
+        "2018".as_ref(),
+        "--score-file".as_ref(),
+        scores.as_os_str(),
+        "--history-file".as_ref(),
+        history.as_os_str(),
+        "--log".as_ref(),
+        path.as_os_str(),
+    ];
+    let game = run(env!("CARGO_BIN_EXE_guessing_game_ext_lib"), args, &format!("abc\n{}\n{}\n", wrong, secret));
+    assert!(game.success());
+
+    let log = fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = log.lines().collect();
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[0], "{\"event\":\"start\",\"version\":1,\"min\":1,\"max\":100,\"max_attempts\":null,\"seed\":2018}");
+    assert_eq!(
+        lines[1],
+        "{\"event\":\"guess\",\"input\":\"abc\",\"rejected\":\"not_a_number\",\"message\":\"'abc'은(는) 숫자가 아닙니다.\"}"
+    );
+    assert_eq!(lines[2], format!("{{\"event\":\"guess\",\"input\":\"{0}\",\"value\":{0}}}", wrong));
+    assert_eq!(lines[3], format!("{{\"event\":\"compare\",\"attempt\":1,\"guess\":{},\"ordering\":\"less\"}}", wrong));
+    assert_eq!(lines[5], format!("{{\"event\":\"compare\",\"attempt\":2,\"guess\":{},\"ordering\":\"equal\"}}", secret));
+    assert!(lines[6].starts_with("{\"event\":\"end\",\"outcome\":\"won\",\"attempts\":2,\"duration_ms\":"));
+}
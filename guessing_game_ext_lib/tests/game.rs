@@ -0,0 +1,26 @@
+extern crate guessing_game_ext_lib;
+extern crate test_support;
+
+use guessing_game_ext_lib::{Game, Outcome};
+use test_support::FakeIo;
+
+#[test]
+fn full_game_transcript() {
+    let mut game = Game::new(42);
+    let mut io = FakeIo::new("abc\n50\n42\n");
+    let outcome = game.play(&mut io.input, &mut io.output).unwrap();
+
+    assert_eq!(outcome, Outcome::Won(2));
+    let expected = "\
+1 부터 100 사이의 숫자를 맞춰보세요.
+숫자를 입력하세여
+'abc'은(는) 숫자가 아닙니다.
+숫자를 입력하세여
+님이 입력한 숫자는 50 입니다.
+너무 큼
+숫자를 입력하세여
+님이 입력한 숫자는 42 입니다.
+님이 맞춤
+";
+    assert_eq!(io.output(), expected);
+}
@@ -0,0 +1,31 @@
+extern crate guessing_game_ext_lib;
+extern crate test_support;
+
+use std::fs;
+use std::time::Duration;
+
+use guessing_game_ext_lib::{Config, Score, ScoreBoard};
+use test_support::TempDir;
+
+#[test]
+fn missing_file_is_created() {
+    let dir = TempDir::new("guessing_game_scores");
+    let path = dir.join("scores.txt");
+    let board = ScoreBoard::load(&path).unwrap();
+    assert!(board.scores.is_empty());
+    assert!(path.exists());
+}
+
+#[test]
+fn corrupted_lines_are_skipped() {
+    let dir = TempDir::new("guessing_game_scores");
+    let path = dir.join("scores.txt");
+    let score = Score::new("영희", &Config::default(), 6, Duration::from_secs(3));
+    ScoreBoard::append(&path, &score).unwrap();
+    fs::write(&path, [fs::read(&path).unwrap(), b"garbage\n\xff\xfe\n".to_vec()].concat()).unwrap();
+    ScoreBoard::append(&path, &score).unwrap();
+
+    let board = ScoreBoard::load(&path).unwrap();
+    assert_eq!(board.scores, vec![score.clone(), score]);
+    assert_eq!(board.skipped, 2);
+}
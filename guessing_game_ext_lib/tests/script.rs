@@ -0,0 +1,32 @@
+extern crate test_support;
+
+use std::fs;
+
+use test_support::{run, seeded_secret_and_miss, TempDir};
+
+const BIN: &str = env!("CARGO_BIN_EXE_guessing_game_ext_lib");
+
+#[test]
+fn script_file_sets_exit_code() {
+    let (secret, wrong) = seeded_secret_and_miss(7);
+    let dir = TempDir::new("guessing_game_script");
+    let path = dir.join("guesses.txt");
+    let script = path.to_str().unwrap();
+
+    fs::write(&path, format!("# 고정된 시드 7\n{}\n{}\n", wrong, secret)).unwrap();
+    let won = run(BIN, ["--seed", "7", "--script", script], "");
+    assert_eq!(won.code, Some(0));
+    assert!(!won.stdout.contains("숫자를 입력하세여"));
+    assert!(won.stdout.ends_with("님이 맞춤\n"));
+
+    fs::write(&path, format!("{}\n", wrong)).unwrap();
+    let lost = run(BIN, ["--seed", "7", "--attempts", "1", "--script", script], "");
+    assert_eq!(lost.code, Some(1));
+}
+
+#[test]
+fn piped_script_stops_at_invalid_line() {
+    let args = ["--seed", "7", "--script", "-"];
+    assert_eq!(run(BIN, args, "abc\n").code, Some(3));
+    assert_eq!(run(BIN, args, "").code, Some(4));
+}
@@ -0,0 +1,68 @@
+extern crate guessing_game_ext_lib;
+extern crate test_support;
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::thread;
+
+use guessing_game_ext_lib::server::Server;
+use guessing_game_ext_lib::Config;
+use test_support::{run, seeded_secret_and_miss};
+
+fn start_server(seed: u32) -> SocketAddr {
+    let server = Server::bind("127.0.0.1:0", Config::default(), seed).unwrap();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+    addr
+}
+
+/// 서버에 접속해서 이분 탐색으로 맞추고 (시도 횟수, 맞춘 숫자)를 돌려준다.
+fn bisect(addr: SocketAddr) -> (u32, u32) {
+    let stream = TcpStream::connect(addr).unwrap();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut stream = stream;
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    assert_eq!(line, "READY 1 100 -\n");
+
+    let (mut low, mut high) = (1, 100);
+    loop {
+        let guess = low + (high - low) / 2;
+        writeln!(stream, "GUESS {}", guess).unwrap();
+        line.clear();
+        reader.read_line(&mut line).unwrap();
+        match line.trim() {
+            "LOW" => low = guess + 1,
+            "HIGH" => high = guess - 1,
+            reply if reply.starts_with("WIN ") => return (reply[4..].parse().unwrap(), guess),
+            reply => panic!("예상하지 못한 응답: {}", reply),
+        }
+    }
+}
+
+#[test]
+fn concurrent_clients_on_loopback() {
+    let addr = start_server(11);
+    // 먼저 접속만 해두고 아무것도 보내지 않는 클라이언트가 있어도 다른 클라이언트는 진행되어야 한다.
+    let idle = TcpStream::connect(addr).unwrap();
+    let handles: Vec<_> = (0..8).map(|_| thread::spawn(move || bisect(addr))).collect();
+    for handle in handles {
+        let (attempts, secret) = handle.join().unwrap();
+        assert!(attempts <= 7);
+        assert!((1..=100).contains(&secret));
+    }
+    drop(idle);
+}
+
+#[test]
+fn bundled_client_binary() {
+    let seed = 5;
+    let addr = start_server(seed);
+    // 서버는 접속한 순서대로 시드에서 비밀 숫자를 뽑으므로 첫 클라이언트의 숫자를 미리 알 수 있다.
+    let (secret, wrong) = seeded_secret_and_miss(seed);
+    let output = run(env!("CARGO_BIN_EXE_guessing_client"), [addr.to_string()], &format!("abc\n{}\n{}\n", wrong, secret));
+
+    assert!(output.success());
+    assert!(output.stdout.contains("'abc'은(는) 숫자가 아닙니다."));
+    assert!(output.stdout.ends_with("님이 맞춤 (2번 만에)\n"));
+}
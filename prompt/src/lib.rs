@@ -0,0 +1,209 @@
+/*
+대화형 프로그램에서 값을 하나 물어보고 받아오는 도우미.
+
+guessing_game은 read_line(...).expect(...)로 읽고 개행까지 그대로 출력했고,
+guessing_game_ext_lib은 "묻기 → 읽기 → 파싱 → 틀리면 이유를 알려주고 다시 묻기" 루프를 곳곳에서 따로 짰다.
+그 루프를 여기로 모았다.
+
+- 입력 줄의 앞뒤 공백과 개행은 잘라낸 뒤 파싱한다.
+- UTF-8이 아닌 바이트는 �(U+FFFD)로 바꿔서 넘긴다. 읽기 오류로 끝내지 않고 파서가 거절하게 한다.
+- 파싱이나 검사에 실패하면 이유를 출력하고 다시 묻는다.
+- 입력이 끝난 것(EOF)과 입출력 오류는 panic 하지 않고 PromptError 값으로 돌려준다.
+- 읽는 곳과 쓰는 곳은 BufRead, Write면 무엇이든 되므로 테스트에서는 바이트 배열과 Vec을 넘기면 된다.
+*/
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+/// 값을 받지 못하고 끝난 이유
+#[derive(Debug)]
+pub enum PromptError {
+    /// 값을 받기 전에 입력이 끝났다.
+    Eof,
+    /// 읽거나 쓰다가 오류가 났다.
+    Io(io::Error),
+}
+
+impl fmt::Display for PromptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PromptError::Eof => write!(f, "입력이 끝났습니다."),
+            PromptError::Io(error) => write!(f, "입출력 실패: {}", error),
+        }
+    }
+}
+
+impl Error for PromptError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PromptError::Eof => None,
+            PromptError::Io(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for PromptError {
+    fn from(error: io::Error) -> PromptError {
+        PromptError::Io(error)
+    }
+}
+
+/// 읽는 곳과 쓰는 곳을 묶어 둔 것. 한 번 만들어서 여러 번 물어볼 수 있다.
+#[derive(Debug)]
+pub struct Prompt<R, W> {
+    input: R,
+    output: W,
+}
+
+impl<R: BufRead, W: Write> Prompt<R, W> {
+    pub fn new(input: R, output: W) -> Prompt<R, W> {
+        Prompt { input, output }
+    }
+
+    /// message를 출력하고 한 줄을 읽어 T로 파싱한다.
+    /// 파싱에 실패하면 입력과 T::Err를, validator가 Err를 돌려주면 그 문장을 출력하고 다시 묻는다.
+    pub fn ask<T, F>(&mut self, message: &str, validator: F) -> Result<T, PromptError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+        F: Fn(&T) -> Result<(), String>,
+    {
+        self.ask_with(message, |text| {
+            let value = text.parse::<T>().map_err(|error| format!("'{}'을(를) 읽을 수 없습니다. ({})", text, error))?;
+            validator(&value)?;
+            Ok::<T, String>(value)
+        })
+    }
+
+    /// ask와 같지만 FromStr 대신 parse로 바꾼다. 게임 범위처럼 파싱할 때 알아야 하는 값이 있을 때 쓴다.
+    /// parse에는 앞뒤 공백을 잘라낸 줄이 들어간다.
+    /// message는 물을 때마다 새로 출력하므로 남은 시간처럼 그때그때 바뀌는 문장은 Display를 구현해서 넘기면 된다.
+    pub fn ask_with<M, T, E, F>(&mut self, message: M, mut parse: F) -> Result<T, PromptError>
+    where
+        M: fmt::Display,
+        E: fmt::Display,
+        F: FnMut(&str) -> Result<T, E>,
+    {
+        loop {
+            writeln!(self.output, "{}", message)?;
+            self.output.flush()?;
+            let mut bytes = Vec::new();
+            if self.input.read_until(b'\n', &mut bytes)? == 0 {
+                return Err(PromptError::Eof);
+            }
+            let line = String::from_utf8_lossy(&bytes);
+            match parse(line.trim()) {
+                Ok(value) => return Ok(value),
+                Err(error) => writeln!(self.output, "{}", error)?,
+            }
+        }
+    }
+
+    /// 물어보는 사이에 다른 것을 출력할 때 쓴다.
+    pub fn output(&mut self) -> &mut W {
+        &mut self.output
+    }
+
+    pub fn into_inner(self) -> (R, W) {
+        (self.input, self.output)
+    }
+}
+
+/// 표준 입력에서 T를 하나 받는다. Prompt::ask를 표준 입출력으로 부르는 것과 같다.
+pub fn prompt<T, F>(message: &str, validator: F) -> Result<T, PromptError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    F: Fn(&T) -> Result<(), String>,
+{
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    Prompt::new(stdin.lock(), stdout.lock()).ask(message, validator)
+}
+
+/// 아무 값이나 받아들이는 validator
+pub fn any<T>(_: &T) -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn even(value: &u32) -> Result<(), String> {
+        if value.is_multiple_of(2) {
+            Ok(())
+        } else {
+            Err(format!("{}은(는) 짝수가 아닙니다.", value))
+        }
+    }
+
+    #[test]
+    fn trims_and_parses() {
+        let mut output = Vec::new();
+        let value: u32 = Prompt::new(&b"  42 \r\n"[..], &mut output).ask("숫자?", any).unwrap();
+        assert_eq!(value, 42);
+        assert_eq!(output, "숫자?\n".as_bytes());
+    }
+
+    #[test]
+    fn retries_on_parse_and_validation_failure() {
+        let mut output = Vec::new();
+        let value = Prompt::new(&b"abc\n7\n8\n"[..], &mut output).ask("짝수?", even).unwrap();
+        assert_eq!(value, 8);
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output, "짝수?\n'abc'을(를) 읽을 수 없습니다. (invalid digit found in string)\n짝수?\n7은(는) 짝수가 아닙니다.\n짝수?\n");
+    }
+
+    #[test]
+    fn custom_parser() {
+        let mut prompt = Prompt::new(&b"x\ny\n"[..], io::sink());
+        let value = prompt.ask_with("y?", |text| if text == "y" { Ok(true) } else { Err("y만 됩니다.") });
+        assert!(value.unwrap());
+    }
+
+    #[test]
+    fn message_is_written_again_on_each_ask() {
+        struct Counter(std::cell::Cell<u32>);
+        impl fmt::Display for Counter {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.set(self.0.get() + 1);
+                write!(f, "{}번째 질문", self.0.get())
+            }
+        }
+        let mut output = Vec::new();
+        let value: u32 = Prompt::new(&b"x\n1\n"[..], &mut output).ask_with(Counter(std::cell::Cell::new(0)), |text| text.parse()).unwrap();
+        assert_eq!(value, 1);
+        assert_eq!(String::from_utf8(output).unwrap(), "1번째 질문\ninvalid digit found in string\n2번째 질문\n");
+    }
+
+    #[test]
+    fn non_utf8_line_goes_to_the_parser() {
+        let mut output = Vec::new();
+        let value: u32 = Prompt::new(&b"\xff\n8\n"[..], &mut output).ask("숫자?", any).unwrap();
+        assert_eq!(value, 8);
+        assert!(String::from_utf8(output).unwrap().contains("'\u{fffd}'을(를) 읽을 수 없습니다."));
+    }
+
+    #[test]
+    fn eof_is_a_value() {
+        let result = Prompt::new(&b"abc\n"[..], io::sink()).ask::<u32, _>("숫자?", any);
+        assert!(matches!(result, Err(PromptError::Eof)));
+    }
+
+    #[test]
+    fn io_error_is_a_value() {
+        struct Broken;
+        impl io::Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("고장"))
+            }
+        }
+        let result = Prompt::new(io::BufReader::new(Broken), io::sink()).ask::<u32, _>("숫자?", any);
+        match result {
+            Err(PromptError::Io(error)) => assert_eq!(error.to_string(), "고장"),
+            other => panic!("{:?}", other),
+        }
+    }
+}
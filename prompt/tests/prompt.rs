@@ -0,0 +1,17 @@
+extern crate prompt;
+extern crate test_support;
+
+use prompt::{Prompt, PromptError};
+use test_support::FakeIo;
+
+#[test]
+fn asks_several_values_in_a_row() {
+    let mut io = FakeIo::new("홍길동\n-3\n30\n");
+    let mut prompt = Prompt::new(&mut io.input, &mut io.output);
+    let name: String = prompt.ask("이름?", |name: &String| if name.is_empty() { Err("이름을 입력하세요.".to_string()) } else { Ok(()) }).unwrap();
+    let age: u8 = prompt.ask("나이?", prompt::any).unwrap();
+    assert!(matches!(prompt.ask::<u8, _>("또?", prompt::any), Err(PromptError::Eof)));
+
+    assert_eq!((name.as_str(), age), ("홍길동", 30));
+    assert_eq!(io.lines(), vec!["이름?", "나이?", "'-3'을(를) 읽을 수 없습니다. (invalid digit found in string)", "나이?", "또?"]);
+}
@@ -0,0 +1,216 @@
+/*
+저장소의 통합 테스트가 같이 쓰는 도우미.
+
+처음에는 11_test/adder/tests/comm/mod.rs에 get_num() 하나만 있었다.
+tests/ 아래의 파일은 각각 따로 컴파일되는 crate라서 공통 코드를 tests/comm/mod.rs 같은 하위 모듈에 두고 mod comm;으로 가져온다.
+그런데 이 방법으로는 다른 crate의 테스트와 코드를 나눌 수 없으므로 crate로 빼고 각 crate의 dev-dependencies에 path로 적었다.
+
+- TempDir: 테스트가 끝나면 지워지는 임시 디렉터리
+- FakeIo: BufRead/Write를 받는 함수에 넘길 가짜 stdin/stdout
+- run: 바이너리에 입력을 넣고 종료 코드와 출력을 받아온다.
+- panic_message, assert_panics_with: panic 문장 검사
+- seeded_secret_and_miss: 시드를 준 게임의 비밀 숫자와 틀린 추측 하나
+*/
+extern crate rand;
+
+use std::any::Any;
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::{Cursor, Write};
+use std::panic::{self, UnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use rand::{Rng, SeedableRng, XorShiftRng};
+
+/// 같은 프로세스 안에서 동시에 도는 테스트끼리 디렉터리가 겹치지 않도록 붙이는 번호
+static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+/// 시스템 임시 디렉터리 아래에 만드는 빈 디렉터리. drop 될 때 안의 파일과 함께 지운다.
+/// 이름에 프로세스 번호와 일련번호를 붙이므로 테스트가 병렬로 돌아도 서로 겹치지 않는다.
+#[derive(Debug)]
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    pub fn new(prefix: &str) -> TempDir {
+        let number = NEXT_DIR.fetch_add(1, Ordering::SeqCst);
+        let path = env::temp_dir().join(format!("{}_{}_{}", prefix, std::process::id(), number));
+        // 예전에 죽은 테스트가 남긴 디렉터리가 있으면 비우고 시작한다.
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).expect("임시 디렉터리를 만들 수 없습니다");
+        TempDir { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 디렉터리 안의 파일 경로. 파일을 만들지는 않는다.
+    pub fn join<P: AsRef<Path>>(&self, name: P) -> PathBuf {
+        self.path.join(name)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// 가짜 표준 입출력. input은 BufRead, output은 Write 이므로
+/// `game.play(&mut io.input, &mut io.output)` 처럼 가변 참조로 넘기고 나중에 output()으로 출력을 본다.
+#[derive(Debug)]
+pub struct FakeIo {
+    pub input: Cursor<Vec<u8>>,
+    pub output: Vec<u8>,
+}
+
+impl FakeIo {
+    pub fn new(input: &str) -> FakeIo {
+        FakeIo { input: Cursor::new(input.as_bytes().to_vec()), output: Vec::new() }
+    }
+
+    /// 지금까지 쓴 출력. UTF-8이 아니면 panic 한다.
+    pub fn output(&self) -> String {
+        String::from_utf8(self.output.clone()).expect("출력이 UTF-8이 아닙니다")
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.output().lines().map(String::from).collect()
+    }
+}
+
+/// 바이너리를 한 번 실행한 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    /// 종료 코드. 시그널로 죽었으면 None
+    pub code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl Run {
+    pub fn success(&self) -> bool {
+        self.code == Some(0)
+    }
+}
+
+/// 인수 없이 실행할 때 run의 args로 넘긴다.
+pub const NO_ARGS: [&str; 0] = [];
+
+/// program을 args로 실행하고 input을 표준 입력으로 넣은 뒤 끝날 때까지 기다린다.
+/// 통합 테스트에서는 program에 env!("CARGO_BIN_EXE_<이름>")을 넘기면 된다.
+pub fn run<P, I, S>(program: P, args: I, input: &str) -> Run
+where
+    P: AsRef<OsStr>,
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("바이너리를 실행할 수 없습니다");
+    // 입력을 다 쓰기 전에 출력 파이프가 차서 서로 기다리지 않도록 입력은 다른 스레드에서 쓴다.
+    // 입력을 다 읽지 않고 끝나는 프로그램도 있으므로 쓰기 오류(EPIPE)는 무시한다.
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.as_bytes().to_vec();
+    let writer = thread::spawn(move || {
+        let _ = stdin.write_all(&input);
+    });
+    let output = child.wait_with_output().expect("바이너리 실행 실패");
+    writer.join().unwrap();
+    Run {
+        code: output.status.code(),
+        stdout: String::from_utf8(output.stdout).expect("표준 출력이 UTF-8이 아닙니다"),
+        stderr: String::from_utf8(output.stderr).expect("표준 오류가 UTF-8이 아닙니다"),
+    }
+}
+
+/// f를 실행해서 panic 하면 그 문장을, 아니면 None을 돌려준다.
+pub fn panic_message<F: FnOnce() -> R + UnwindSafe, R>(f: F) -> Option<String> {
+    panic::catch_unwind(f).err().map(|payload| payload_text(&*payload))
+}
+
+/// panic!("...")은 &str, panic!("{}", x)는 String을 담아서 던진다.
+fn payload_text(payload: &(dyn Any + Send)) -> String {
+    if let Some(text) = payload.downcast_ref::<&str>() {
+        text.to_string()
+    } else if let Some(text) = payload.downcast_ref::<String>() {
+        text.clone()
+    } else {
+        "(문자열이 아닌 panic)".to_string()
+    }
+}
+
+/// f가 expected를 포함한 문장으로 panic 하는지 확인한다. #[should_panic(expected = ...)]과 같지만
+/// 테스트 하나에서 여러 번 확인하거나 문장을 만들어서 비교할 때 쓴다.
+pub fn assert_panics_with<F: FnOnce() -> R + UnwindSafe, R>(f: F, expected: &str) {
+    match panic_message(f) {
+        Some(message) => assert!(message.contains(expected), "panic 문장 {:?}에 {:?}이(가) 없습니다.", message, expected),
+        None => panic!("{:?}(으)로 panic 해야 하는데 panic 하지 않았습니다.", expected),
+    }
+}
+
+/// guessing_game_ext_lib를 --seed seed로 기본 범위(1 ~ 100)에서 돌렸을 때의 비밀 숫자와 그 숫자가 아닌 추측 하나.
+/// test_support는 게임 crate에 의존할 수 없으므로 guessing_game_ext_lib::seeded_rng와 Game::with_rng를 그대로 따라 한다.
+/// (둘이 어긋나지 않는지는 guessing_game_ext_lib의 game.rs 테스트에서 확인한다.)
+pub fn seeded_secret_and_miss(seed: u32) -> (u32, u32) {
+    let mut rng = XorShiftRng::from_seed([seed, 0x193a_6754, 0xa8a7_d469, 0x9783_0e05]);
+    let secret = rng.gen_range(1u64, 101) as u32;
+    let miss = if secret == 1 { 2 } else { 1 };
+    (secret, miss)
+}
+
+/// adder::Guess::new가 범위 밖의 value를 받았을 때의 panic 문장
+pub fn guess_range_message(min: u32, max: u32, value: u32) -> String {
+    format!("Guess value must be between {} and {}, got {}.", min, max, value)
+}
+
+/// Guess가 아닌 adder::Bounded<min, max>::new가 범위 밖의 value를 받았을 때의 panic 문장
+pub fn range_message(min: u32, max: u32, value: u32) -> String {
+    format!("Value must be between {} and {}, got {}.", min, max, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufRead;
+
+    #[test]
+    fn temp_dir_is_removed_on_drop() {
+        let dir = TempDir::new("test_support");
+        let other = TempDir::new("test_support");
+        assert_ne!(dir.path(), other.path());
+        fs::write(dir.join("a.txt"), "a").unwrap();
+        let path = dir.path().to_path_buf();
+        drop(dir);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn fake_io() {
+        let mut io = FakeIo::new("첫 줄\n둘째 줄\n");
+        let mut line = String::new();
+        io.input.read_line(&mut line).unwrap();
+        writeln!(io.output, "읽음: {}", line.trim()).unwrap();
+        assert_eq!(io.lines(), vec!["읽음: 첫 줄"]);
+    }
+
+    #[test]
+    fn panic_messages() {
+        assert_eq!(panic_message(|| panic!("고정")), Some("고정".to_string()));
+        assert_eq!(panic_message(|| panic!("{}", 42)), Some("42".to_string()));
+        assert_eq!(panic_message(|| 1), None);
+        assert_panics_with(|| panic!("{}", guess_range_message(1, 100, 200)), "between 1 and 100, got 200.");
+        assert_panics_with(|| panic!("{}", range_message(1, 6, 7)), "Value must be between 1 and 6, got 7.");
+        assert!(panic_message(|| assert_panics_with(|| (), "x")).is_some());
+    }
+}